@@ -0,0 +1,61 @@
+//! Benchmarks for the construction of non-empty collections.
+//!
+//! They compare `NonEmptyCollection::new` with the previous emptiness check, which cloned the whole
+//! input collection to look at its first element, on collections holding one million elements.
+
+use catalyser::stdx::collections::{NonEmptyHashMap, NonEmptyVec};
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use std::{collections::HashMap, hint::black_box};
+
+const ELEMENT_COUNT: usize = 1_000_000;
+
+fn bench_non_empty_vec_new(c: &mut Criterion) {
+    let data: Vec<usize> = (0..ELEMENT_COUNT).collect();
+    let mut group = c.benchmark_group("non_empty_vec_new");
+
+    group.bench_function("length_check", |b| {
+        b.iter_batched(
+            || data.clone(),
+            |vec| black_box(NonEmptyVec::new(vec)),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("clone_check", |b| {
+        b.iter_batched(
+            || data.clone(),
+            |vec| black_box(vec.clone().into_iter().next().is_some().then_some(vec)),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.finish();
+}
+
+fn bench_non_empty_hash_map_new(c: &mut Criterion) {
+    let data: HashMap<usize, usize> = (0..ELEMENT_COUNT).map(|it| (it, it)).collect();
+    let mut group = c.benchmark_group("non_empty_hash_map_new");
+
+    group.bench_function("length_check", |b| {
+        b.iter_batched(
+            || data.clone(),
+            |map| black_box(NonEmptyHashMap::new(map)),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("clone_check", |b| {
+        b.iter_batched(
+            || data.clone(),
+            |map| black_box(map.clone().into_iter().next().is_some().then_some(map)),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_non_empty_vec_new,
+    bench_non_empty_hash_map_new
+);
+criterion_main!(benches);
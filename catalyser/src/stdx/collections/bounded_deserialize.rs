@@ -0,0 +1,418 @@
+//! This module provides a streaming deserialization for collections, checking their length while
+//! the elements are read rather than after the whole collection has been buffered.
+//!
+//! The `Deserialize` implementation of `BoundedCollection` relies on it: an empty sequence or map
+//! is rejected as soon as its end is reached, and the deserialization is aborted as soon as the
+//! collection holds more than `MAX` elements, so oversized payloads are never fully allocated.
+//!
+//! # Features
+//!
+//! - `BoundedDeserialize` deserializes a collection with runtime minimum and maximum lengths, and
+//!   is implemented for the standard collections, `UniqueVec`, `SortedVec` and the third-party
+//!   collections.
+//! - `impl_bounded_deserialize!` implements `BoundedDeserialize` for a user-defined collection
+//!   with a single line, through the public `SeqVisitor` and `MapVisitor`.
+//! - `deserialize_bounded` can be used with `#[serde(deserialize_with = "...")]` to limit the
+//!   length of a plain collection with a caller-supplied maximum.
+//!
+//! # Usage Example
+//!
+//! ```rust
+//! use catalyser::stdx::collections::{BoundedVec, NonEmptyVec};
+//!
+//! let non_empty_vec: NonEmptyVec<i32> = serde_json::from_str("[1,2,3]").unwrap();
+//! assert_eq!(non_empty_vec.len().get(), 3);
+//!
+//! let empty: Result<NonEmptyVec<i32>, _> = serde_json::from_str("[]");
+//! assert!(empty.is_err());
+//!
+//! // Aborted on the fourth element, without reading the rest of the payload.
+//! let too_many: Result<BoundedVec<i32, 1, 3>, _> = serde_json::from_str("[1,2,3,4,5,6]");
+//! assert!(too_many.unwrap_err().to_string().contains("too many elements"));
+//! ```
+
+use crate::stdx::{collections::Length, error::is_empty_sequence::SequenceContentError};
+use serde::{
+    de::{Error, MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque},
+    fmt::{self, Formatter},
+    hash::{BuildHasher, Hash},
+    marker::PhantomData,
+};
+
+/// A trait for collections which can be deserialized while checking their length on the fly.
+pub trait BoundedDeserialize<'de>: Sized {
+    /// Deserializes the collection, failing as soon as it holds more than `max` elements, and
+    /// once fully read if it holds fewer than `min` elements.
+    ///
+    /// # Parameters
+    /// - `deserializer`: The deserializer to read the collection from.
+    /// - `min`: The minimum number of elements of the collection.
+    /// - `max`: The maximum number of elements of the collection.
+    ///
+    /// # Returns
+    /// - `Ok(Self)` if the collection holds between `min` and `max` elements.
+    /// - `Err(D::Error)` with a `SequenceContentError` message if it does not, or with the error
+    ///   of the deserializer.
+    fn deserialize_bounded<D>(deserializer: D, min: usize, max: usize) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+}
+
+/// Deserializes a collection holding at most `MAX` elements, for use with
+/// `#[serde(deserialize_with = "...")]`.
+///
+/// # Parameters
+/// - `deserializer`: The deserializer to read the collection from.
+///
+/// # Returns
+/// - The deserialized collection, or an error as soon as it holds more than `MAX` elements.
+///
+/// # Usage Example
+///
+/// ```rust
+/// use catalyser::stdx::collections::bounded_deserialize::deserialize_bounded;
+///
+/// #[derive(serde::Deserialize)]
+/// struct Request {
+///     #[serde(deserialize_with = "deserialize_bounded::<_, _, 2>")]
+///     ids: Vec<u32>,
+/// }
+///
+/// assert!(serde_json::from_str::<Request>(r#"{"ids":[1,2]}"#).is_ok());
+/// assert!(serde_json::from_str::<Request>(r#"{"ids":[1,2,3]}"#).is_err());
+/// ```
+pub fn deserialize_bounded<'de, C, D, const MAX: usize>(deserializer: D) -> Result<C, D::Error>
+where
+    C: BoundedDeserialize<'de>,
+    D: Deserializer<'de>,
+{
+    C::deserialize_bounded(deserializer, 0, MAX)
+}
+
+/// Checks the length of a collection being deserialized against `max`.
+///
+/// # Returns
+///
+/// - `Err(E)` with a `SequenceContentError::TooMany` message if `len` is greater than `max`.
+pub fn check_max<E: Error>(len: usize, max: usize) -> Result<(), E> {
+    if len > max {
+        Err(E::custom(SequenceContentError::TooMany {
+            max,
+            actual: len,
+        }))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks the length of a fully deserialized collection against `min`.
+///
+/// # Returns
+///
+/// - `Err(E)` with a `SequenceContentError::Empty` or `SequenceContentError::TooFew` message if
+///   `len` is less than `min`.
+pub fn check_min<E: Error>(len: usize, min: usize) -> Result<(), E> {
+    if len >= min {
+        Ok(())
+    } else if len == 0 {
+        Err(E::custom(SequenceContentError::Empty))
+    } else {
+        Err(E::custom(SequenceContentError::TooFew { min, actual: len }))
+    }
+}
+
+/// Describes the expected length of a collection being deserialized, for `Visitor::expecting`.
+pub fn expecting_length(formatter: &mut Formatter, kind: &str, min: usize, max: usize) -> fmt::Result {
+    match (min, max) {
+        (0, usize::MAX) => write!(formatter, "a {kind}"),
+        (1, usize::MAX) => write!(formatter, "a non-empty {kind}"),
+        (min, usize::MAX) => write!(formatter, "a {kind} of at least {min} elements"),
+        (min, max) => write!(formatter, "a {kind} of {min} to {max} elements"),
+    }
+}
+
+/// A visitor extending a collection with the elements of a sequence, checking its length against
+/// the minimum and maximum it was created with.
+pub struct SeqVisitor<C, T> {
+    min: usize,
+    max: usize,
+    marker: PhantomData<fn() -> (C, T)>,
+}
+
+impl<C, T> SeqVisitor<C, T> {
+    /// Creates a new visitor for a collection holding between `min` and `max` elements.
+    pub fn new(min: usize, max: usize) -> Self {
+        Self {
+            min,
+            max,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, C, T> Visitor<'de> for SeqVisitor<C, T>
+where
+    C: Default + Extend<T> + Length,
+    T: Deserialize<'de>,
+{
+    type Value = C;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        expecting_length(formatter, "sequence", self.min, self.max)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut collection = C::default();
+        while let Some(element) = seq.next_element()? {
+            collection.extend(Some(element));
+            check_max(collection.len(), self.max)?;
+        }
+        check_min(collection.len(), self.min)?;
+        Ok(collection)
+    }
+}
+
+/// A visitor extending a collection with the entries `E` of a map, checking its length against
+/// the minimum and maximum it was created with.
+pub struct MapVisitor<C, E> {
+    min: usize,
+    max: usize,
+    marker: PhantomData<fn() -> (C, E)>,
+}
+
+impl<C, E> MapVisitor<C, E> {
+    /// Creates a new visitor for a collection holding between `min` and `max` entries.
+    pub fn new(min: usize, max: usize) -> Self {
+        Self {
+            min,
+            max,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, C, K, V> Visitor<'de> for MapVisitor<C, (K, V)>
+where
+    C: Default + Extend<(K, V)> + Length,
+    K: Deserialize<'de>,
+    V: Deserialize<'de>,
+{
+    type Value = C;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        expecting_length(formatter, "map", self.min, self.max)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut collection = C::default();
+        while let Some(entry) = map.next_entry()? {
+            collection.extend(Some(entry));
+            check_max(collection.len(), self.max)?;
+        }
+        check_min(collection.len(), self.min)?;
+        Ok(collection)
+    }
+}
+
+/// Implements [BoundedDeserialize] for a collection deserialized from a sequence (`seq`) or a map
+/// (`map`), through [SeqVisitor] or [MapVisitor].
+///
+/// The collection must implement `Default`, `Extend` of its items and [Length], so that a
+/// user-defined collection can be deserialized as a `BoundedCollection` with a single line.
+///
+/// # Examples
+///
+/// ```rust
+/// use catalyser::{
+///     impl_bounded_deserialize,
+///     stdx::collections::{Length, NonEmptyCollection},
+/// };
+///
+/// #[derive(Default)]
+/// struct Tags(Vec<String>);
+///
+/// impl IntoIterator for Tags {
+///     type Item = String;
+///     type IntoIter = std::vec::IntoIter<String>;
+///
+///     fn into_iter(self) -> Self::IntoIter {
+///         self.0.into_iter()
+///     }
+/// }
+///
+/// impl Extend<String> for Tags {
+///     fn extend<I: IntoIterator<Item = String>>(&mut self, iter: I) {
+///         self.0.extend(iter)
+///     }
+/// }
+///
+/// impl Length for Tags {
+///     fn len(&self) -> usize {
+///         self.0.len()
+///     }
+/// }
+///
+/// impl_bounded_deserialize!(seq impl Tags => String);
+///
+/// let tags: NonEmptyCollection<String, Tags> = serde_json::from_str(r#"["rust","serde"]"#).unwrap();
+/// assert_eq!(tags.len().get(), 2);
+/// assert!(serde_json::from_str::<NonEmptyCollection<String, Tags>>("[]").is_err());
+/// ```
+#[macro_export]
+macro_rules! impl_bounded_deserialize {
+    (seq impl<$($generic:ident),+> $collection_type:ty => $item_type:ty $(where $($bound:tt)+)?) => {
+        $crate::impl_bounded_deserialize!(@seq [$($generic),+] $collection_type => $item_type $(where $($bound)+)?);
+    };
+    (seq impl $collection_type:ty => $item_type:ty $(where $($bound:tt)+)?) => {
+        $crate::impl_bounded_deserialize!(@seq [] $collection_type => $item_type $(where $($bound)+)?);
+    };
+    (map impl<$($generic:ident),+> $collection_type:ty => ($key_type:ty, $value_type:ty) $(where $($bound:tt)+)?) => {
+        $crate::impl_bounded_deserialize!(@map [$($generic),+] $collection_type => ($key_type, $value_type) $(where $($bound)+)?);
+    };
+    (map impl $collection_type:ty => ($key_type:ty, $value_type:ty) $(where $($bound:tt)+)?) => {
+        $crate::impl_bounded_deserialize!(@map [] $collection_type => ($key_type, $value_type) $(where $($bound)+)?);
+    };
+    (@seq [$($generic:ident),*] $collection_type:ty => $item_type:ty $(where $($bound:tt)+)?) => {
+        impl<'de, $($generic),*> $crate::stdx::collections::bounded_deserialize::BoundedDeserialize<'de> for $collection_type
+        where
+            $item_type: serde::Deserialize<'de>,
+            $($($bound)+)?
+        {
+            fn deserialize_bounded<D>(deserializer: D, min: usize, max: usize) -> ::core::result::Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                deserializer.deserialize_seq($crate::stdx::collections::bounded_deserialize::SeqVisitor::new(min, max))
+            }
+        }
+    };
+    (@map [$($generic:ident),*] $collection_type:ty => ($key_type:ty, $value_type:ty) $(where $($bound:tt)+)?) => {
+        impl<'de, $($generic),*> $crate::stdx::collections::bounded_deserialize::BoundedDeserialize<'de> for $collection_type
+        where
+            $key_type: serde::Deserialize<'de>,
+            $value_type: serde::Deserialize<'de>,
+            $($($bound)+)?
+        {
+            fn deserialize_bounded<D>(deserializer: D, min: usize, max: usize) -> ::core::result::Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                deserializer.deserialize_map($crate::stdx::collections::bounded_deserialize::MapVisitor::new(min, max))
+            }
+        }
+    };
+}
+
+pub(crate) use crate::impl_bounded_deserialize;
+
+impl_bounded_deserialize!(seq impl<T> Vec<T> => T);
+impl_bounded_deserialize!(seq impl<T> VecDeque<T> => T);
+impl_bounded_deserialize!(seq impl<T> LinkedList<T> => T);
+impl_bounded_deserialize!(seq impl<T> BTreeSet<T> => T where T: Ord);
+impl_bounded_deserialize!(seq impl<T, S> HashSet<T, S> => T where T: Eq + Hash, S: BuildHasher + Default);
+impl_bounded_deserialize!(seq impl<T> BinaryHeap<T> => T where T: Ord);
+impl_bounded_deserialize!(map impl<K, V> BTreeMap<K, V> => (K, V) where K: Ord);
+impl_bounded_deserialize!(map impl<K, V, S> HashMap<K, V, S> => (K, V) where K: Eq + Hash, S: BuildHasher + Default);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stdx::collections::{BoundedCollection, BoundedVec, NonEmptyBTreeMap, NonEmptyHashSet, NonEmptyVec};
+    use serde::de::value::{Error as ValueError, SeqDeserializer};
+
+    #[test]
+    fn test_bounded_deserialize_aborts_early() {
+        // An endless sequence can only be deserialized by aborting as soon as `MAX` is exceeded.
+        let endless = SeqDeserializer::<_, ValueError>::new(0u32..);
+        let result = BoundedVec::<u32, 1, 8>::deserialize(endless);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "sequence has too many elements (maximum: 8, actual: 9)"
+        );
+
+        let endless = SeqDeserializer::<_, ValueError>::new(0u32..);
+        let result = deserialize_bounded::<Vec<u32>, _, 3>(endless);
+        assert!(result.is_err());
+    }
+
+    /// A user-defined collection keeping its elements in reverse order.
+    #[derive(Debug, Default, PartialEq)]
+    struct Stack(Vec<i32>);
+
+    impl IntoIterator for Stack {
+        type Item = i32;
+        type IntoIter = std::iter::Rev<std::vec::IntoIter<i32>>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.0.into_iter().rev()
+        }
+    }
+
+    impl Extend<i32> for Stack {
+        fn extend<I: IntoIterator<Item = i32>>(&mut self, iter: I) {
+            self.0.extend(iter)
+        }
+    }
+
+    impl Length for Stack {
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+    }
+
+    impl_bounded_deserialize!(seq impl Stack => i32);
+
+    #[test]
+    fn test_bounded_deserialize_user_collection() {
+        let stack: BoundedCollection<i32, Stack, 1, 3> = serde_json::from_str("[1,2,3]").unwrap();
+        assert_eq!(stack.into_iter().collect::<Vec<_>>(), vec![3, 2, 1]);
+
+        let empty = serde_json::from_str::<BoundedCollection<i32, Stack, 1, 3>>("[]");
+        assert!(empty.unwrap_err().to_string().contains("sequence is empty"));
+
+        let endless = SeqDeserializer::<_, ValueError>::new(0i32..);
+        let result = BoundedCollection::<i32, Stack, 1, 3>::deserialize(endless);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "sequence has too many elements (maximum: 3, actual: 4)"
+        );
+    }
+
+    #[test]
+    fn test_bounded_deserialize_length() {
+        let empty: Result<NonEmptyVec<i32>, _> = serde_json::from_str("[]");
+        assert!(empty.unwrap_err().to_string().contains("sequence is empty"));
+
+        let empty: Result<NonEmptyBTreeMap<String, i32>, _> = serde_json::from_str("{}");
+        assert!(empty.unwrap_err().to_string().contains("sequence is empty"));
+
+        let too_few: Result<BoundedVec<i32, 2, 3>, _> = serde_json::from_str("[1]");
+        assert!(too_few
+            .unwrap_err()
+            .to_string()
+            .contains("too few elements"));
+
+        // Duplicates are not counted, as the length is checked on the collection itself.
+        let set: BoundedCollection<i32, HashSet<i32>, 1, 2> = serde_json::from_str("[1,1,1,2]").unwrap();
+        assert_eq!(set.len(), 2);
+
+        let non_empty_hash_set: NonEmptyHashSet<i32> = serde_json::from_str("[1,2]").unwrap();
+        assert_eq!(non_empty_hash_set.len().get(), 2);
+
+        let wrong_type: Result<NonEmptyVec<i32>, _> = serde_json::from_str("{}");
+        assert!(wrong_type
+            .unwrap_err()
+            .to_string()
+            .contains("a non-empty sequence"));
+    }
+}
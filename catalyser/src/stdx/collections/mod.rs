@@ -0,0 +1,2695 @@
+//! This module defines a generic wrapper, `BoundedCollection`, to ensure that collections hold a
+//! number of elements within const generic bounds, and its most common case, `NonEmptyCollection`,
+//! to ensure that collections are non-empty. It provides implementations for commonly used Rust
+//! collections, such as `HashSet`, `Vec`, `VecDeque`, `BTreeMap`, and others, and verifies their
+//! length at runtime.
+//!
+//! The module also includes utility methods, serialization and deserialization support via Serde,
+//! and specialized types for specific non-empty collections.
+//!
+//! # Features
+//!
+//! - `NonEmptyCollection` ensures collections are not empty when constructed, in constant time
+//!   and without copying the input.
+//! - `BoundedCollection` generalizes the check to any `MIN..=MAX` number of elements (e.g. "at
+//!   least 2 points", "exactly 3 components"), reporting `TooFew` / `TooMany` errors.
+//! - `ValidatedCollection` validates every element of a collection, reporting the index of each
+//!   invalid one.
+//! - `UniqueVec` keeps the insertion order of its elements while rejecting duplicates.
+//! - `SortedVec` keeps its elements sorted according to a strict or non-strict, possibly custom,
+//!   order, with binary search, ordered insertion, merge and range queries.
+//! - Supports several collection types (e.g., `Vec`, `BTreeSet`, `HashSet`, `BinaryHeap`).
+//! - Seamless handling of serialization and deserialization using Serde, rejecting empty or
+//!   oversized payloads while they are read, with an opt-in sorted serialization for hash-based
+//!   collections.
+//! - Borrows the inner collection through `as_inner`, `Deref`, `AsRef` and `Borrow`, so a
+//!   non-empty collection can be read wherever the plain collection is expected.
+//! - `NonEmptySequence` provides infallible `first`, `last`, `minimum`, `maximum`, `reduce` and
+//!   `fold1` operations.
+//! - Transforms its elements through `NonEmptyIter` adaptors (`map`, `zip`, `enumerate`,
+//!   `flat_map`) without losing the non-empty guarantee.
+//! - Reports its length as a `NonZeroUsize` for any collection implementing `Length`.
+//! - Marks the collections that can never end up empty once extended with an element through
+//!   `GrowableCollection`.
+//! - Mutating operations (`push`, `insert`, `truncate`, `pop`, `retain`, ...) that can never leave
+//!   the collection empty.
+//! - Converts from and to the standard collections with `TryFrom` / `From`, and between non-empty
+//!   collections without losing the non-empty guarantee.
+//! - Provides infallible `from_head_tail` and `singleton` constructors for the collections
+//!   implementing `GrowableCollection`.
+//! - Provides construction macros (`non_empty_vec!`, `non_empty_set!`, `non_empty_map!`, ...)
+//!   rejecting empty invocations at compile time.
+//! - Borrows a `NonEmptyVec` as a `NonEmptySlice`, so non-empty data can be passed by reference.
+//! - Provides unchecked creation for use cases where the non-empty constraint is guaranteed by
+//!   logic.
+//!
+//! # Usage Example
+//!
+//! ```rust
+//! use catalyser::stdx::collections::NonEmptyVec;
+//!
+//! let data = vec![1, 2, 3];
+//! let non_empty_vec = NonEmptyVec::new(data).unwrap();
+//! assert_eq!(non_empty_vec.into_inner(), vec![1, 2, 3]);
+//!
+//! let empty_data: Vec<i32> = vec![];
+//! let result = NonEmptyVec::new(empty_data);
+//! assert!(result.is_err());
+//!
+//! use catalyser::stdx::collections::BoundedVec;
+//!
+//! let rgb = BoundedVec::<u8, 3, 3>::new(vec![255, 128, 0]);
+//! assert!(rgb.is_ok());
+//!
+//! let too_few = BoundedVec::<u8, 3, 3>::new(vec![255, 128]);
+//! assert!(too_few.is_err());
+//! ```
+//!
+//! # Submodules
+//!
+//! - `bounded_deserialize`: Provides a streaming deserialization checking the length of
+//!   collections while their elements are read (requires the `serde` feature).
+//! - `non_empty_iter`: Provides `NonEmptyIter`, an iterator wrapper keeping the non-empty
+//!   guarantee through transformations.
+//! - `non_empty_slice`: Provides `NonEmptySlice`, a borrowed view over a non-empty slice.
+//! - `validated_collection`: Provides `ValidatedCollection`, a wrapper ensuring every element of a
+//!   collection satisfies an `ElementValidator`.
+//! - `unique_vec`: Provides `UniqueVec` and `NonEmptyUniqueVec`, insertion-ordered vectors
+//!   rejecting duplicate elements.
+//! - `sorted_vec`: Provides `SortedVec` and `NonEmptySortedVec`, vectors kept sorted according to
+//!   a type-level order.
+//! - `sorted_serde`: Provides a deterministic, sorted serialization for hash-based collections
+//!   (requires the `serde` feature).
+//! - `third_party`: Provides non-empty collections for third-party collection libraries, behind
+//!   cargo features.
+
+#[cfg(feature = "serde")]
+pub mod bounded_deserialize;
+pub mod non_empty_iter;
+pub mod non_empty_slice;
+#[cfg(feature = "serde")]
+pub mod sorted_serde;
+pub mod sorted_vec;
+pub mod third_party;
+pub mod unique_vec;
+pub mod validated_collection;
+
+use crate::stdx::{
+    collections::{
+        non_empty_iter::{IntoNonEmptyIterator, NonEmptyIter},
+        non_empty_slice::{NonEmptyChunks, NonEmptySlice, NonEmptyWindows},
+        sorted_vec::{NonEmptySortedVec, SortedVec},
+        unique_vec::{NonEmptyUniqueVec, UniqueVec},
+    },
+    error::is_empty_sequence::SequenceContentError,
+};
+#[cfg(feature = "serde")]
+use bounded_deserialize::BoundedDeserialize;
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Serialize};
+use std::{
+    borrow::Borrow,
+    collections::{btree_map, hash_map, linked_list, vec_deque, BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque},
+    hash::Hash,
+    iter, mem,
+    num::NonZeroUsize,
+    ops::Deref,
+    slice,
+};
+
+/// A generic collection wrapper holding between `MIN` and `MAX` elements (both inclusive).
+///
+/// Bounds where `MIN` is greater than `MAX` are rejected at compile time.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct BoundedCollection<T, C, const MIN: usize, const MAX: usize>(C)
+where
+    C: IntoIterator<Item = T> + Default;
+
+/// A generic non-empty collection wrapper, i.e. a [BoundedCollection] holding at least one
+/// element.
+pub type NonEmptyCollection<T, C> = BoundedCollection<T, C, 1, { usize::MAX }>;
+
+impl<T, C, const MIN: usize, const MAX: usize> BoundedCollection<T, C, MIN, MAX>
+where
+    C: IntoIterator<Item = T> + Default + Length,
+{
+    /// Creates a new bounded collection.
+    ///
+    /// The length check relies on [Length], so it runs in constant time for the standard
+    /// collections and never copies the input.
+    ///
+    /// # Parameters
+    ///
+    /// - `collection`: The input collection.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Self)` if the collection holds between `MIN` and `MAX` elements.
+    /// - `Err(SequenceContentError::Empty)` if the collection is empty and `MIN` is not `0`.
+    /// - `Err(SequenceContentError::TooFew)` if the collection holds less than `MIN` elements.
+    /// - `Err(SequenceContentError::TooMany)` if the collection holds more than `MAX` elements.
+    pub fn new(collection: C) -> Result<Self, SequenceContentError> {
+        const { assert!(MIN <= MAX, "`MIN` must be less than or equal to `MAX`") };
+
+        let actual = collection.len();
+        if actual < MIN {
+            if actual == 0 {
+                Err(SequenceContentError::Empty)
+            } else {
+                Err(SequenceContentError::TooFew { min: MIN, actual })
+            }
+        } else if actual > MAX {
+            Err(SequenceContentError::TooMany { max: MAX, actual })
+        } else {
+            Ok(Self(collection))
+        }
+    }
+}
+
+impl<T, C> NonEmptyCollection<T, C>
+where
+    C: IntoIterator<Item = T> + Default + Length,
+{
+    /// Returns the number of elements in the collection.
+    ///
+    /// The length is reported as a `NonZeroUsize`, so it can be used as a divisor without any
+    /// further check.
+    pub fn len(&self) -> NonZeroUsize {
+        NonZeroUsize::new(self.0.len()).expect(NON_EMPTY_INVARIANT)
+    }
+
+    /// Returns `false`, as the collection always contains at least one element.
+    ///
+    /// This method only shadows the `is_empty` of the inner collection, otherwise reachable
+    /// through `Deref`, so that the useless check is reported as deprecated.
+    #[deprecated(note = "a non-empty collection is never empty, so this always returns `false`")]
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+impl<T, C> NonEmptyCollection<T, C>
+where
+    C: IntoIterator<Item = T> + Default + Extend<T> + GrowableCollection,
+{
+    /// Creates a new non-empty collection from a required first element and the remaining ones.
+    ///
+    /// This constructor is infallible, as the collection always contains at least `head`. It is
+    /// only available for the collections implementing [GrowableCollection], which are trusted to
+    /// keep `head` once extended with it.
+    ///
+    /// # Parameters
+    ///
+    /// - `head`: The first element of the collection.
+    /// - `tail`: The remaining (possibly zero) elements of the collection.
+    ///
+    /// # Returns
+    ///
+    /// - `Self`: A new non-empty collection.
+    ///
+    /// # Examples
+    ///
+    /// A collection that may drop the elements it is extended with is rejected:
+    ///
+    /// ```compile_fail
+    /// use catalyser::stdx::collections::NonEmptyCollection;
+    ///
+    /// #[derive(Default)]
+    /// struct Evens(Vec<i32>);
+    ///
+    /// impl Extend<i32> for Evens {
+    ///     fn extend<I: IntoIterator<Item = i32>>(&mut self, iter: I) {
+    ///         self.0.extend(iter.into_iter().filter(|it| it % 2 == 0))
+    ///     }
+    /// }
+    ///
+    /// impl IntoIterator for Evens {
+    ///     type Item = i32;
+    ///     type IntoIter = std::vec::IntoIter<i32>;
+    ///
+    ///     fn into_iter(self) -> Self::IntoIter {
+    ///         self.0.into_iter()
+    ///     }
+    /// }
+    ///
+    /// let evens = NonEmptyCollection::<i32, Evens>::from_head_tail(1, [3]);
+    /// ```
+    pub fn from_head_tail<I>(head: T, tail: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut collection = C::default();
+        collection.extend(iter::once(head));
+        collection.extend(tail);
+        Self(collection)
+    }
+
+    /// Creates a new non-empty collection containing a single element.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The only element of the collection.
+    ///
+    /// # Returns
+    ///
+    /// - `Self`: A new non-empty collection.
+    pub fn singleton(value: T) -> Self {
+        Self::from_head_tail(value, iter::empty())
+    }
+}
+
+impl<T, C, const MIN: usize, const MAX: usize> BoundedCollection<T, C, MIN, MAX>
+where
+    C: IntoIterator<Item = T> + Default,
+{
+    /// Creates a new bounded collection.
+    ///
+    /// # Parameters
+    ///
+    /// - `collection`: The input collection.
+    ///
+    /// # Returns
+    ///
+    /// - `Self`: A new bounded collection.
+    ///
+    /// # Safety
+    ///
+    /// This function assumes that the collection holds between `MIN` and `MAX` elements.
+    pub unsafe fn new_unchecked(collection: C) -> Self {
+        Self(collection)
+    }
+
+    /// Returns the inner collection.
+    pub fn into_inner(self) -> C {
+        self.0
+    }
+
+    /// Returns a reference to the inner collection.
+    pub fn as_inner(&self) -> &C {
+        &self.0
+    }
+}
+
+impl<T, C> NonEmptyCollection<T, C>
+where
+    C: IntoIterator<Item = T> + Default,
+{
+    /// Returns a non-empty iterator over the borrowed elements of the collection.
+    pub fn iter<'a>(&'a self) -> NonEmptyIter<<&'a C as IntoIterator>::IntoIter>
+    where
+        &'a C: IntoIterator,
+    {
+        self.into_non_empty_iter()
+    }
+}
+
+impl<T, C, const MIN: usize, const MAX: usize> Deref for BoundedCollection<T, C, MIN, MAX>
+where
+    C: IntoIterator<Item = T> + Default,
+{
+    type Target = C;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, C, const MIN: usize, const MAX: usize> AsRef<C> for BoundedCollection<T, C, MIN, MAX>
+where
+    C: IntoIterator<Item = T> + Default,
+{
+    fn as_ref(&self) -> &C {
+        &self.0
+    }
+}
+
+impl<T, C, const MIN: usize, const MAX: usize> Borrow<C> for BoundedCollection<T, C, MIN, MAX>
+where
+    C: IntoIterator<Item = T> + Default,
+{
+    fn borrow(&self) -> &C {
+        &self.0
+    }
+}
+
+impl<T, C, const MIN: usize, const MAX: usize> IntoIterator for BoundedCollection<T, C, MIN, MAX>
+where
+    C: IntoIterator<Item = T> + Default,
+{
+    type Item = T;
+    type IntoIter = C::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T, C, const MIN: usize, const MAX: usize> IntoIterator for &'a BoundedCollection<T, C, MIN, MAX>
+where
+    C: IntoIterator<Item = T> + Default,
+    &'a C: IntoIterator,
+{
+    type Item = <&'a C as IntoIterator>::Item;
+    type IntoIter = <&'a C as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self.0).into_iter()
+    }
+}
+
+/// Panic message for the branches that are unreachable as long as the non-empty invariant holds.
+const NON_EMPTY_INVARIANT: &str = "non-empty collection invariant violated";
+
+/// A trait exposing the infallible operations of a non-empty collection.
+///
+/// Since the collection is known to contain at least one element, these methods return bare values
+/// instead of an `Option`.
+///
+/// The element-wise extrema are named `minimum` and `maximum` because `NonEmptyCollection` itself
+/// implements `Ord`, whose `min` and `max` methods would otherwise take precedence.
+///
+/// # Examples
+///
+/// ```rust
+/// use catalyser::stdx::collections::{NonEmptySequence, NonEmptyVec};
+///
+/// let non_empty_vec = NonEmptyVec::new(vec![3, 1, 2]).unwrap();
+/// assert_eq!(non_empty_vec.first(), &3);
+/// assert_eq!(non_empty_vec.last(), &2);
+/// assert_eq!(non_empty_vec.minimum(), &1);
+/// assert_eq!(non_empty_vec.maximum(), &3);
+/// assert_eq!(non_empty_vec.reduce(|acc, it| acc + it), 6);
+/// ```
+pub trait NonEmptySequence {
+    /// The type of the owned elements of the collection.
+    type Item;
+
+    /// The type of the borrowed elements of the collection (e.g. `&T` for sequences and sets, or
+    /// `(&K, &V)` for maps).
+    type ItemRef<'a>
+    where
+        Self: 'a;
+
+    /// Returns the first element of the collection, in iteration order.
+    fn first(&self) -> Self::ItemRef<'_>;
+
+    /// Returns the last element of the collection, in iteration order.
+    fn last(&self) -> Self::ItemRef<'_>;
+
+    /// Returns the minimum element of the collection.
+    ///
+    /// If several elements are equally minimum, the first one is returned.
+    fn minimum<'a>(&'a self) -> Self::ItemRef<'a>
+    where
+        Self::ItemRef<'a>: Ord;
+
+    /// Returns the maximum element of the collection.
+    ///
+    /// If several elements are equally maximum, the last one is returned.
+    fn maximum<'a>(&'a self) -> Self::ItemRef<'a>
+    where
+        Self::ItemRef<'a>: Ord;
+
+    /// Reduces the elements to a single one, by repeatedly applying `f`.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: The reducing function, called with the accumulator and the next element.
+    ///
+    /// # Returns
+    ///
+    /// - `Self::Item`: The reduced value, which is the first element if the collection contains a
+    ///   single element.
+    fn reduce<F>(self, f: F) -> Self::Item
+    where
+        F: FnMut(Self::Item, Self::Item) -> Self::Item;
+
+    /// Folds the elements into an accumulator seeded from the first element.
+    ///
+    /// # Parameters
+    ///
+    /// - `init`: The function building the initial accumulator from the first element.
+    /// - `f`: The folding function, called with the accumulator and each remaining element.
+    ///
+    /// # Returns
+    ///
+    /// - `B`: The final accumulator.
+    fn fold1<B, I, F>(self, init: I, f: F) -> B
+    where
+        I: FnOnce(Self::Item) -> B,
+        F: FnMut(B, Self::Item) -> B;
+}
+
+impl<T, C> NonEmptySequence for NonEmptyCollection<T, C>
+where
+    C: IntoIterator<Item = T> + Default,
+    for<'a> &'a C: IntoIterator,
+{
+    type Item = T;
+    type ItemRef<'a>
+        = <&'a C as IntoIterator>::Item
+    where
+        Self: 'a;
+
+    fn first(&self) -> Self::ItemRef<'_> {
+        (&self.0).into_iter().next().expect(NON_EMPTY_INVARIANT)
+    }
+
+    fn last(&self) -> Self::ItemRef<'_> {
+        (&self.0).into_iter().last().expect(NON_EMPTY_INVARIANT)
+    }
+
+    fn minimum<'a>(&'a self) -> Self::ItemRef<'a>
+    where
+        Self::ItemRef<'a>: Ord,
+    {
+        (&self.0).into_iter().min().expect(NON_EMPTY_INVARIANT)
+    }
+
+    fn maximum<'a>(&'a self) -> Self::ItemRef<'a>
+    where
+        Self::ItemRef<'a>: Ord,
+    {
+        (&self.0).into_iter().max().expect(NON_EMPTY_INVARIANT)
+    }
+
+    fn reduce<F>(self, f: F) -> Self::Item
+    where
+        F: FnMut(Self::Item, Self::Item) -> Self::Item,
+    {
+        self.0.into_iter().reduce(f).expect(NON_EMPTY_INVARIANT)
+    }
+
+    fn fold1<B, I, F>(self, init: I, f: F) -> B
+    where
+        I: FnOnce(Self::Item) -> B,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut iter = self.0.into_iter();
+        let first = iter.next().expect(NON_EMPTY_INVARIANT);
+        iter.fold(init(first), f)
+    }
+}
+
+/// A trait for collections able to report their number of elements cheaply.
+///
+/// It is implemented for the standard collections and can be implemented for user-defined
+/// collections, to be wrapped in a [BoundedCollection] or a [NonEmptyCollection].
+pub trait Length {
+    /// Returns the number of elements in the collection.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the collection contains no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+macro_rules! impl_length {
+    ($collection_type:ident<$($generic:ident),+> $(where $($bound:tt)+)?) => {
+        impl<$($generic),+> Length for $collection_type<$($generic),+> $(where $($bound)+)? {
+            fn len(&self) -> usize {
+                $collection_type::len(self)
+            }
+
+            fn is_empty(&self) -> bool {
+                $collection_type::is_empty(self)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_length;
+
+impl_length!(Vec<T>);
+impl_length!(VecDeque<T>);
+impl_length!(LinkedList<T>);
+impl_length!(BTreeSet<T>);
+impl_length!(HashSet<T, S>);
+impl_length!(BTreeMap<K, V>);
+impl_length!(HashMap<K, V, S>);
+impl_length!(BinaryHeap<T>);
+
+/// A marker trait for collections that always hold at least one element once extended with, or
+/// collected from, at least one element.
+///
+/// It is implemented for the standard collections, whose capacity is unbounded and whose `Extend`
+/// and `FromIterator` implementations keep every element (up to duplicates for sets and maps). The
+/// operations relying on this guarantee instead of checking the length, such as `from_head_tail`,
+/// `singleton`, `Extend` or `NonEmptyIter::collect_non_empty`, are only available for these
+/// collections.
+///
+/// # Safety
+///
+/// Implementors must guarantee that extending an instance, or collecting an iterator, with at least
+/// one element yields a non-empty collection, without panicking for lack of capacity.
+pub unsafe trait GrowableCollection {}
+
+// SAFETY: the standard collections have an unbounded capacity and keep every element they are
+// extended with, up to duplicates for sets and maps.
+unsafe impl<T> GrowableCollection for Vec<T> {}
+unsafe impl<T> GrowableCollection for VecDeque<T> {}
+unsafe impl<T> GrowableCollection for LinkedList<T> {}
+unsafe impl<T> GrowableCollection for BTreeSet<T> {}
+unsafe impl<T, S> GrowableCollection for HashSet<T, S> {}
+unsafe impl<K, V> GrowableCollection for BTreeMap<K, V> {}
+unsafe impl<K, V, S> GrowableCollection for HashMap<K, V, S> {}
+unsafe impl<T> GrowableCollection for BinaryHeap<T> {}
+
+#[cfg(feature = "serde")]
+impl<'de, T, C, const MIN: usize, const MAX: usize> Deserialize<'de> for BoundedCollection<T, C, MIN, MAX>
+where
+    C: IntoIterator<Item = T> + Default + Length + BoundedDeserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match C::deserialize_bounded(deserializer, MIN, MAX).map(Self::new)? {
+            Ok(result) => Ok(result),
+            Err(err) => Err(D::Error::custom(err)),
+        }
+    }
+}
+
+/// Specialized type for non-empty ordered set based on a B-Tree.
+pub type NonEmptyBTreeSet<T> = NonEmptyCollection<T, BTreeSet<T>>;
+
+/// Specialized type for non-empty hash sets.
+pub type NonEmptyHashSet<T> = NonEmptyCollection<T, HashSet<T>>;
+
+/// Specialized type for non-empty vectors.
+pub type NonEmptyVec<T> = NonEmptyCollection<T, Vec<T>>;
+
+/// Specialized type for non-empty double-ended queue.
+pub type NonEmptyVecDeque<T> = NonEmptyCollection<T, VecDeque<T>>;
+
+/// Specialized type for non-empty doubly-linked list with owned nodes.
+pub type NonEmptyLinkedList<T> = NonEmptyCollection<T, LinkedList<T>>;
+
+/// Specialized type for non-empty sorted B-tree map.
+pub type NonEmptyBTreeMap<K, V> = NonEmptyCollection<(K, V), BTreeMap<K, V>>;
+
+/// Specialized type for non-empty hash map.
+pub type NonEmptyHashMap<K, V> = NonEmptyCollection<(K, V), HashMap<K, V>>;
+
+/// Specialized type for non-empty priority queue implemented with a binary heap.
+pub type NonEmptyBinaryHeap<T> = NonEmptyCollection<T, BinaryHeap<T>>;
+
+/// Specialized type for vectors holding between `MIN` and `MAX` elements.
+pub type BoundedVec<T, const MIN: usize, const MAX: usize> = BoundedCollection<T, Vec<T>, MIN, MAX>;
+
+macro_rules! impl_collection_conversion {
+    (impl<$($generic:ident),+> $collection_type:ty => $item_type:ty $(where $($bound:tt)+)?) => {
+        impl<$($generic),+, const MIN: usize, const MAX: usize> TryFrom<$collection_type> for BoundedCollection<$item_type, $collection_type, MIN, MAX> $(where $($bound)+)? {
+            type Error = SequenceContentError;
+
+            fn try_from(collection: $collection_type) -> Result<Self, Self::Error> {
+                Self::new(collection)
+            }
+        }
+
+        impl<$($generic),+, const MIN: usize, const MAX: usize> From<BoundedCollection<$item_type, $collection_type, MIN, MAX>> for $collection_type $(where $($bound)+)? {
+            fn from(collection: BoundedCollection<$item_type, $collection_type, MIN, MAX>) -> Self {
+                collection.0
+            }
+        }
+    };
+}
+
+pub(crate) use impl_collection_conversion;
+
+impl_collection_conversion!(impl<T> BTreeSet<T> => T);
+impl_collection_conversion!(impl<T> HashSet<T> => T);
+impl_collection_conversion!(impl<T> Vec<T> => T);
+impl_collection_conversion!(impl<T> VecDeque<T> => T);
+impl_collection_conversion!(impl<T> LinkedList<T> => T);
+impl_collection_conversion!(impl<K, V> BTreeMap<K, V> => (K, V));
+impl_collection_conversion!(impl<K, V> HashMap<K, V> => (K, V));
+impl_collection_conversion!(impl<T> BinaryHeap<T> => T where T: Ord);
+impl_collection_conversion!(impl<T> UniqueVec<T> => T);
+impl_collection_conversion!(impl<T, O, P> SortedVec<T, O, P> => T);
+
+macro_rules! impl_nonempty_collection_conversion {
+    (impl<$($generic:ident),+> $from_type:ty => $to_type:ty $(where $($bound:tt)+)?) => {
+        impl<$($generic),+> From<$from_type> for $to_type $(where $($bound)+)? {
+            fn from(collection: $from_type) -> Self {
+                BoundedCollection(collection.0.into_iter().collect())
+            }
+        }
+    };
+}
+
+pub(crate) use impl_nonempty_collection_conversion;
+
+impl_nonempty_collection_conversion!(impl<T> NonEmptyVec<T> => NonEmptyVecDeque<T>);
+impl_nonempty_collection_conversion!(impl<T> NonEmptyVec<T> => NonEmptyLinkedList<T>);
+impl_nonempty_collection_conversion!(impl<T> NonEmptyVec<T> => NonEmptyBTreeSet<T> where T: Ord);
+impl_nonempty_collection_conversion!(impl<T> NonEmptyVec<T> => NonEmptyHashSet<T> where T: Eq + Hash);
+impl_nonempty_collection_conversion!(impl<T> NonEmptyVecDeque<T> => NonEmptyVec<T>);
+impl_nonempty_collection_conversion!(impl<T> NonEmptyLinkedList<T> => NonEmptyVec<T>);
+impl_nonempty_collection_conversion!(impl<T> NonEmptyBTreeSet<T> => NonEmptyVec<T>);
+impl_nonempty_collection_conversion!(impl<T> NonEmptyVec<T> => NonEmptyBinaryHeap<T> where T: Ord);
+impl_nonempty_collection_conversion!(impl<T> NonEmptyHashSet<T> => NonEmptyVec<T>);
+impl_nonempty_collection_conversion!(impl<T> NonEmptyBinaryHeap<T> => NonEmptyVec<T> where T: Ord);
+impl_nonempty_collection_conversion!(impl<T> NonEmptyUniqueVec<T> => NonEmptyVec<T>);
+impl_nonempty_collection_conversion!(impl<T, O, P> NonEmptySortedVec<T, O, P> => NonEmptyVec<T>);
+impl_nonempty_collection_conversion!(impl<K, V> NonEmptyBTreeMap<K, V> => NonEmptyHashMap<K, V> where K: Eq + Hash);
+impl_nonempty_collection_conversion!(impl<K, V> NonEmptyHashMap<K, V> => NonEmptyBTreeMap<K, V> where K: Ord);
+
+/// Creates a [NonEmptyVec] containing the arguments.
+///
+/// At least one element is required, so the non-empty guarantee is checked at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// use catalyser::non_empty_vec;
+///
+/// let non_empty_vec = non_empty_vec![1, 2, 3];
+/// assert_eq!(non_empty_vec.into_inner(), vec![1, 2, 3]);
+/// ```
+///
+/// An empty invocation is rejected:
+///
+/// ```compile_fail
+/// use catalyser::{non_empty_vec, stdx::collections::NonEmptyVec};
+///
+/// let non_empty_vec: NonEmptyVec<i32> = non_empty_vec![];
+/// ```
+#[macro_export]
+macro_rules! non_empty_vec {
+    () => {
+        compile_error!("`non_empty_vec!` requires at least one element")
+    };
+    ($head:expr $(, $tail:expr)* $(,)?) => {
+        $crate::stdx::collections::NonEmptyVec::from_head_tail($head, [$($tail),*])
+    };
+}
+
+/// Creates a [NonEmptyHashSet] containing the arguments.
+///
+/// At least one element is required, so the non-empty guarantee is checked at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// use catalyser::non_empty_set;
+/// use std::collections::HashSet;
+///
+/// let non_empty_set = non_empty_set![1, 2, 2];
+/// assert_eq!(non_empty_set.into_inner(), HashSet::from([1, 2]));
+/// ```
+///
+/// An empty invocation is rejected:
+///
+/// ```compile_fail
+/// use catalyser::{non_empty_set, stdx::collections::NonEmptyHashSet};
+///
+/// let non_empty_set: NonEmptyHashSet<i32> = non_empty_set![];
+/// ```
+#[macro_export]
+macro_rules! non_empty_set {
+    () => {
+        compile_error!("`non_empty_set!` requires at least one element")
+    };
+    ($head:expr $(, $tail:expr)* $(,)?) => {
+        $crate::stdx::collections::NonEmptyHashSet::from_head_tail($head, [$($tail),*])
+    };
+}
+
+/// Creates a [NonEmptyBTreeSet] containing the arguments.
+///
+/// At least one element is required, so the non-empty guarantee is checked at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// use catalyser::non_empty_btree_set;
+/// use std::collections::BTreeSet;
+///
+/// let non_empty_btree_set = non_empty_btree_set![3, 1, 2];
+/// assert_eq!(non_empty_btree_set.into_inner(), BTreeSet::from([1, 2, 3]));
+/// ```
+///
+/// An empty invocation is rejected:
+///
+/// ```compile_fail
+/// use catalyser::{non_empty_btree_set, stdx::collections::NonEmptyBTreeSet};
+///
+/// let non_empty_btree_set: NonEmptyBTreeSet<i32> = non_empty_btree_set![];
+/// ```
+#[macro_export]
+macro_rules! non_empty_btree_set {
+    () => {
+        compile_error!("`non_empty_btree_set!` requires at least one element")
+    };
+    ($head:expr $(, $tail:expr)* $(,)?) => {
+        $crate::stdx::collections::NonEmptyBTreeSet::from_head_tail($head, [$($tail),*])
+    };
+}
+
+/// Creates a [NonEmptyHashMap] containing the `key => value` arguments.
+///
+/// At least one entry is required, so the non-empty guarantee is checked at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// use catalyser::non_empty_map;
+/// use std::collections::HashMap;
+///
+/// let non_empty_map = non_empty_map! { "a" => 1, "b" => 2 };
+/// assert_eq!(non_empty_map.into_inner(), HashMap::from([("a", 1), ("b", 2)]));
+/// ```
+///
+/// An empty invocation is rejected:
+///
+/// ```compile_fail
+/// use catalyser::{non_empty_map, stdx::collections::NonEmptyHashMap};
+///
+/// let non_empty_map: NonEmptyHashMap<&str, i32> = non_empty_map! {};
+/// ```
+#[macro_export]
+macro_rules! non_empty_map {
+    () => {
+        compile_error!("`non_empty_map!` requires at least one entry")
+    };
+    ($head_key:expr => $head_value:expr $(, $key:expr => $value:expr)* $(,)?) => {
+        $crate::stdx::collections::NonEmptyHashMap::from_head_tail(($head_key, $head_value), [$(($key, $value)),*])
+    };
+}
+
+/// Creates a [NonEmptyBTreeMap] containing the `key => value` arguments.
+///
+/// At least one entry is required, so the non-empty guarantee is checked at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// use catalyser::non_empty_btree_map;
+/// use std::collections::BTreeMap;
+///
+/// let non_empty_btree_map = non_empty_btree_map! { "b" => 2, "a" => 1 };
+/// assert_eq!(non_empty_btree_map.into_inner(), BTreeMap::from([("a", 1), ("b", 2)]));
+/// ```
+///
+/// An empty invocation is rejected:
+///
+/// ```compile_fail
+/// use catalyser::{non_empty_btree_map, stdx::collections::NonEmptyBTreeMap};
+///
+/// let non_empty_btree_map: NonEmptyBTreeMap<&str, i32> = non_empty_btree_map! {};
+/// ```
+#[macro_export]
+macro_rules! non_empty_btree_map {
+    () => {
+        compile_error!("`non_empty_btree_map!` requires at least one entry")
+    };
+    ($head_key:expr => $head_value:expr $(, $key:expr => $value:expr)* $(,)?) => {
+        $crate::stdx::collections::NonEmptyBTreeMap::from_head_tail(($head_key, $head_value), [$(($key, $value)),*])
+    };
+}
+
+/// Extending is only available for the collections implementing [GrowableCollection], which are
+/// trusted not to drop their elements nor to run out of capacity.
+impl<T, C, A> Extend<A> for NonEmptyCollection<T, C>
+where
+    C: IntoIterator<Item = T> + Default + Extend<A> + GrowableCollection,
+{
+    fn extend<I: IntoIterator<Item = A>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<T> NonEmptyVec<T> {
+    /// Returns a non-empty slice view over the whole vector.
+    pub fn as_slice(&self) -> &NonEmptySlice<T> {
+        // SAFETY: the vector is non-empty, so is the slice of its elements.
+        unsafe { NonEmptySlice::new_unchecked(self.0.as_slice()) }
+    }
+
+    /// Returns a non-empty iterator over non-empty chunks of `size` elements, the last chunk
+    /// holding the remaining elements if `size` does not divide the length of the vector.
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: The number of elements of each chunk.
+    pub fn chunks_non_empty(&self, size: NonZeroUsize) -> NonEmptyIter<NonEmptyChunks<'_, T>> {
+        self.as_slice().chunks_non_empty(size)
+    }
+
+    /// Returns an iterator over all the overlapping windows of `size` elements. The iterator is
+    /// empty if the vector is shorter than `size`.
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: The number of elements of each window.
+    pub fn windows_non_empty(&self, size: NonZeroUsize) -> NonEmptyWindows<'_, T> {
+        self.as_slice().windows_non_empty(size)
+    }
+
+    /// Splits the vector into owned non-empty chunks of `size` elements, the last chunk holding
+    /// the remaining elements if `size` does not divide the length of the vector.
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: The number of elements of each chunk.
+    ///
+    /// # Returns
+    ///
+    /// - A non-empty vector of the non-empty chunks, in order.
+    pub fn chunked(self, size: NonZeroUsize) -> NonEmptyVec<NonEmptyVec<T>> {
+        let mut elements = self.0.into_iter();
+        let mut chunks = Vec::with_capacity(elements.len().div_ceil(size.get()));
+        loop {
+            let chunk: Vec<T> = elements.by_ref().take(size.get()).collect();
+            if chunk.is_empty() {
+                break;
+            }
+            chunks.push(BoundedCollection(chunk));
+        }
+        BoundedCollection(chunks)
+    }
+
+    /// Returns the first element and the rest of the vector.
+    ///
+    /// # Returns
+    ///
+    /// - `(&T, &[T])`: The first element and the (possibly empty) slice of the remaining elements.
+    pub fn split_first(&self) -> (&T, &[T]) {
+        self.0.split_first().expect(NON_EMPTY_INVARIANT)
+    }
+
+    /// Transforms each element with `f`, keeping the non-empty guarantee.
+    pub fn map<U, F>(self, f: F) -> NonEmptyVec<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        self.into_non_empty_iter().map(f).collect_non_empty()
+    }
+
+    /// Pairs each element with the element at the same position of `other`.
+    ///
+    /// The result is as long as the shorter of the two, hence is non-empty.
+    pub fn zip<U>(self, other: U) -> NonEmptyVec<(T, U::Item)>
+    where
+        U: IntoNonEmptyIterator,
+    {
+        self.into_non_empty_iter().zip(other).collect_non_empty()
+    }
+
+    /// Pairs each element with its index, starting from `0`.
+    pub fn enumerate(self) -> NonEmptyVec<(usize, T)> {
+        self.into_non_empty_iter().enumerate().collect_non_empty()
+    }
+
+    /// Transforms each element into a non-empty iterable with `f` and flattens the result.
+    pub fn flat_map<U, F>(self, f: F) -> NonEmptyVec<U::Item>
+    where
+        U: IntoNonEmptyIterator,
+        F: FnMut(T) -> U,
+    {
+        self.into_non_empty_iter().flat_map(f).collect_non_empty()
+    }
+
+    /// Appends an element to the back of the vector.
+    pub fn push(&mut self, value: T) {
+        self.0.push(value)
+    }
+
+    /// Inserts an element at position `index`, shifting all elements after it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.0.insert(index, value)
+    }
+
+    /// Shortens the vector, keeping the first `len` elements and dropping the rest.
+    ///
+    /// Since `len` is non-zero, the vector can never be truncated to an empty one.
+    pub fn truncate(&mut self, len: NonZeroUsize) {
+        self.0.truncate(len.get())
+    }
+
+    /// Removes the last element of the vector and returns it.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(T)` if the vector contains more than one element.
+    /// - `None` if removing the element would leave the vector empty. The vector is left unchanged.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.0.len() > 1 {
+            self.0.pop()
+        } else {
+            None
+        }
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// The predicate is called exactly once per element, in order.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: The predicate, returning `true` for the elements to keep.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if at least one element was retained.
+    /// - `Err(SequenceContentError::Empty)` if no element matched the predicate. The vector is left
+    ///   unchanged.
+    pub fn retain<F>(&mut self, mut f: F) -> Result<(), SequenceContentError>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let Some(index) = self.0.iter().position(&mut f) else {
+            return Err(SequenceContentError::Empty);
+        };
+        self.0.drain(..index);
+        let mut is_first = true;
+        self.0.retain(|it| mem::take(&mut is_first) || f(it));
+        Ok(())
+    }
+
+    /// Returns a mutable reference to the element at position `index`, or `None` if out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.0.get_mut(index)
+    }
+
+    /// Returns an iterator that allows modifying each element.
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.0.iter_mut()
+    }
+}
+
+impl<T> NonEmptyVecDeque<T> {
+    /// Appends an element to the back of the deque.
+    pub fn push_back(&mut self, value: T) {
+        self.0.push_back(value)
+    }
+
+    /// Prepends an element to the front of the deque.
+    pub fn push_front(&mut self, value: T) {
+        self.0.push_front(value)
+    }
+
+    /// Inserts an element at position `index`, shifting all elements after it to the back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.0.insert(index, value)
+    }
+
+    /// Shortens the deque, keeping the first `len` elements and dropping the rest.
+    ///
+    /// Since `len` is non-zero, the deque can never be truncated to an empty one.
+    pub fn truncate(&mut self, len: NonZeroUsize) {
+        self.0.truncate(len.get())
+    }
+
+    /// Removes the last element of the deque and returns it.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(T)` if the deque contains more than one element.
+    /// - `None` if removing the element would leave the deque empty. The deque is left unchanged.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.0.len() > 1 {
+            self.0.pop_back()
+        } else {
+            None
+        }
+    }
+
+    /// Removes the first element of the deque and returns it.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(T)` if the deque contains more than one element.
+    /// - `None` if removing the element would leave the deque empty. The deque is left unchanged.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.0.len() > 1 {
+            self.0.pop_front()
+        } else {
+            None
+        }
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// The predicate is called exactly once per element, in order.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: The predicate, returning `true` for the elements to keep.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if at least one element was retained.
+    /// - `Err(SequenceContentError::Empty)` if no element matched the predicate. The deque is left
+    ///   unchanged.
+    pub fn retain<F>(&mut self, mut f: F) -> Result<(), SequenceContentError>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let Some(index) = self.0.iter().position(&mut f) else {
+            return Err(SequenceContentError::Empty);
+        };
+        self.0.drain(..index);
+        let mut is_first = true;
+        self.0.retain(|it| mem::take(&mut is_first) || f(it));
+        Ok(())
+    }
+
+    /// Returns a mutable reference to the element at position `index`, or `None` if out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.0.get_mut(index)
+    }
+
+    /// Returns an iterator that allows modifying each element.
+    pub fn iter_mut(&mut self) -> vec_deque::IterMut<'_, T> {
+        self.0.iter_mut()
+    }
+}
+
+impl<T> NonEmptyLinkedList<T> {
+    /// Appends an element to the back of the list.
+    pub fn push_back(&mut self, value: T) {
+        self.0.push_back(value)
+    }
+
+    /// Prepends an element to the front of the list.
+    pub fn push_front(&mut self, value: T) {
+        self.0.push_front(value)
+    }
+
+    /// Shortens the list, keeping the first `len` elements and dropping the rest.
+    ///
+    /// Since `len` is non-zero, the list can never be truncated to an empty one.
+    pub fn truncate(&mut self, len: NonZeroUsize) {
+        if self.0.len() > len.get() {
+            self.0.split_off(len.get());
+        }
+    }
+
+    /// Removes the last element of the list and returns it.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(T)` if the list contains more than one element.
+    /// - `None` if removing the element would leave the list empty. The list is left unchanged.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.0.len() > 1 {
+            self.0.pop_back()
+        } else {
+            None
+        }
+    }
+
+    /// Removes the first element of the list and returns it.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(T)` if the list contains more than one element.
+    /// - `None` if removing the element would leave the list empty. The list is left unchanged.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.0.len() > 1 {
+            self.0.pop_front()
+        } else {
+            None
+        }
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// The predicate is called exactly once per element, in order.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: The predicate, returning `true` for the elements to keep.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if at least one element was retained.
+    /// - `Err(SequenceContentError::Empty)` if no element matched the predicate. The list is left
+    ///   unchanged.
+    pub fn retain<F>(&mut self, f: F) -> Result<(), SequenceContentError>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let (kept, removed): (LinkedList<T>, LinkedList<T>) = mem::take(&mut self.0).into_iter().partition(f);
+        if kept.is_empty() {
+            self.0 = removed;
+            Err(SequenceContentError::Empty)
+        } else {
+            self.0 = kept;
+            Ok(())
+        }
+    }
+
+    /// Returns a mutable reference to the first element of the list.
+    pub fn front_mut(&mut self) -> &mut T {
+        self.0.front_mut().expect(NON_EMPTY_INVARIANT)
+    }
+
+    /// Returns a mutable reference to the last element of the list.
+    pub fn back_mut(&mut self) -> &mut T {
+        self.0.back_mut().expect(NON_EMPTY_INVARIANT)
+    }
+
+    /// Returns an iterator that allows modifying each element.
+    pub fn iter_mut(&mut self) -> linked_list::IterMut<'_, T> {
+        self.0.iter_mut()
+    }
+}
+
+impl<T: Ord> NonEmptyBTreeSet<T> {
+    /// Adds a value to the set.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if the set did not previously contain an equal value.
+    /// - `false` otherwise.
+    pub fn insert(&mut self, value: T) -> bool {
+        self.0.insert(value)
+    }
+
+    /// Removes a value from the set.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The value to remove.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(true)` if the value was present and has been removed.
+    /// - `Ok(false)` if the value was not present.
+    /// - `Err(SequenceContentError::Empty)` if the value is the only element of the set. The set is
+    ///   left unchanged.
+    pub fn remove<Q>(&mut self, value: &Q) -> Result<bool, SequenceContentError>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        if self.0.len() == 1 && self.0.contains(value) {
+            Err(SequenceContentError::Empty)
+        } else {
+            Ok(self.0.remove(value))
+        }
+    }
+
+    /// Removes the first (minimum) element of the set and returns it.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(T)` if the set contains more than one element.
+    /// - `None` if removing the element would leave the set empty. The set is left unchanged.
+    pub fn pop_first(&mut self) -> Option<T> {
+        if self.0.len() > 1 {
+            self.0.pop_first()
+        } else {
+            None
+        }
+    }
+
+    /// Removes the last (maximum) element of the set and returns it.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(T)` if the set contains more than one element.
+    /// - `None` if removing the element would leave the set empty. The set is left unchanged.
+    pub fn pop_last(&mut self) -> Option<T> {
+        if self.0.len() > 1 {
+            self.0.pop_last()
+        } else {
+            None
+        }
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// The predicate is called exactly once per element, in ascending order.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: The predicate, returning `true` for the elements to keep.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if at least one element was retained.
+    /// - `Err(SequenceContentError::Empty)` if no element matched the predicate. The set is left
+    ///   unchanged.
+    pub fn retain<F>(&mut self, f: F) -> Result<(), SequenceContentError>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let (kept, removed): (BTreeSet<T>, BTreeSet<T>) = mem::take(&mut self.0).into_iter().partition(f);
+        if kept.is_empty() {
+            self.0 = removed;
+            Err(SequenceContentError::Empty)
+        } else {
+            self.0 = kept;
+            Ok(())
+        }
+    }
+}
+
+impl<T: Eq + Hash> NonEmptyHashSet<T> {
+    /// Adds a value to the set.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if the set did not previously contain an equal value.
+    /// - `false` otherwise.
+    pub fn insert(&mut self, value: T) -> bool {
+        self.0.insert(value)
+    }
+
+    /// Removes a value from the set.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The value to remove.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(true)` if the value was present and has been removed.
+    /// - `Ok(false)` if the value was not present.
+    /// - `Err(SequenceContentError::Empty)` if the value is the only element of the set. The set is
+    ///   left unchanged.
+    pub fn remove<Q>(&mut self, value: &Q) -> Result<bool, SequenceContentError>
+    where
+        T: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        if self.0.len() == 1 && self.0.contains(value) {
+            Err(SequenceContentError::Empty)
+        } else {
+            Ok(self.0.remove(value))
+        }
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// The predicate is called exactly once per element, in arbitrary order.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: The predicate, returning `true` for the elements to keep.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if at least one element was retained.
+    /// - `Err(SequenceContentError::Empty)` if no element matched the predicate. The set is left
+    ///   with the same elements.
+    pub fn retain<F>(&mut self, f: F) -> Result<(), SequenceContentError>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let (kept, removed): (HashSet<T>, HashSet<T>) = mem::take(&mut self.0).into_iter().partition(f);
+        if kept.is_empty() {
+            self.0 = removed;
+            Err(SequenceContentError::Empty)
+        } else {
+            self.0 = kept;
+            Ok(())
+        }
+    }
+}
+
+impl<K: Ord, V> NonEmptyBTreeMap<K, V> {
+    /// Inserts a key-value pair into the map.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(V)`: The previous value if the map already contained the key.
+    /// - `None` otherwise.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.0.insert(key, value)
+    }
+
+    /// Removes a key from the map.
+    ///
+    /// # Parameters
+    ///
+    /// - `key`: The key to remove.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Some(V))`: The value of the removed key.
+    /// - `Ok(None)` if the key was not present.
+    /// - `Err(SequenceContentError::Empty)` if the key is the only entry of the map. The map is left
+    ///   unchanged.
+    pub fn remove<Q>(&mut self, key: &Q) -> Result<Option<V>, SequenceContentError>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        if self.0.len() == 1 && self.0.contains_key(key) {
+            Err(SequenceContentError::Empty)
+        } else {
+            Ok(self.0.remove(key))
+        }
+    }
+
+    /// Removes the first entry (with the minimum key) of the map and returns it.
+    ///
+    /// # Returns
+    ///
+    /// - `Some((K, V))` if the map contains more than one entry.
+    /// - `None` if removing the entry would leave the map empty. The map is left unchanged.
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        if self.0.len() > 1 {
+            self.0.pop_first()
+        } else {
+            None
+        }
+    }
+
+    /// Removes the last entry (with the maximum key) of the map and returns it.
+    ///
+    /// # Returns
+    ///
+    /// - `Some((K, V))` if the map contains more than one entry.
+    /// - `None` if removing the entry would leave the map empty. The map is left unchanged.
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        if self.0.len() > 1 {
+            self.0.pop_last()
+        } else {
+            None
+        }
+    }
+
+    /// Retains only the entries specified by the predicate.
+    ///
+    /// The predicate is called exactly once per entry, in ascending key order.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: The predicate, returning `true` for the entries to keep.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if at least one entry was retained.
+    /// - `Err(SequenceContentError::Empty)` if no entry matched the predicate. The map keeps all its
+    ///   entries, with the values as left by the predicate.
+    pub fn retain<F>(&mut self, mut f: F) -> Result<(), SequenceContentError>
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        let (mut kept, mut removed) = (BTreeMap::new(), BTreeMap::new());
+        for (key, mut value) in mem::take(&mut self.0) {
+            if f(&key, &mut value) {
+                kept.insert(key, value);
+            } else {
+                removed.insert(key, value);
+            }
+        }
+        if kept.is_empty() {
+            self.0 = removed;
+            Err(SequenceContentError::Empty)
+        } else {
+            self.0 = kept;
+            Ok(())
+        }
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.0.get_mut(key)
+    }
+
+    /// Returns an iterator over the entries of the map, with mutable references to the values.
+    pub fn iter_mut(&mut self) -> btree_map::IterMut<'_, K, V> {
+        self.0.iter_mut()
+    }
+
+    /// Returns an iterator over mutable references to the values of the map.
+    pub fn values_mut(&mut self) -> btree_map::ValuesMut<'_, K, V> {
+        self.0.values_mut()
+    }
+}
+
+impl<K: Eq + Hash, V> NonEmptyHashMap<K, V> {
+    /// Inserts a key-value pair into the map.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(V)`: The previous value if the map already contained the key.
+    /// - `None` otherwise.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.0.insert(key, value)
+    }
+
+    /// Removes a key from the map.
+    ///
+    /// # Parameters
+    ///
+    /// - `key`: The key to remove.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Some(V))`: The value of the removed key.
+    /// - `Ok(None)` if the key was not present.
+    /// - `Err(SequenceContentError::Empty)` if the key is the only entry of the map. The map is left
+    ///   unchanged.
+    pub fn remove<Q>(&mut self, key: &Q) -> Result<Option<V>, SequenceContentError>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        if self.0.len() == 1 && self.0.contains_key(key) {
+            Err(SequenceContentError::Empty)
+        } else {
+            Ok(self.0.remove(key))
+        }
+    }
+
+    /// Retains only the entries specified by the predicate.
+    ///
+    /// The predicate is called exactly once per entry, in arbitrary order.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: The predicate, returning `true` for the entries to keep.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if at least one entry was retained.
+    /// - `Err(SequenceContentError::Empty)` if no entry matched the predicate. The map keeps all its
+    ///   entries, with the values as left by the predicate.
+    pub fn retain<F>(&mut self, mut f: F) -> Result<(), SequenceContentError>
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        let (mut kept, mut removed) = (HashMap::new(), HashMap::new());
+        for (key, mut value) in mem::take(&mut self.0) {
+            if f(&key, &mut value) {
+                kept.insert(key, value);
+            } else {
+                removed.insert(key, value);
+            }
+        }
+        if kept.is_empty() {
+            self.0 = removed;
+            Err(SequenceContentError::Empty)
+        } else {
+            self.0 = kept;
+            Ok(())
+        }
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.0.get_mut(key)
+    }
+
+    /// Returns an iterator over the entries of the map, with mutable references to the values.
+    pub fn iter_mut(&mut self) -> hash_map::IterMut<'_, K, V> {
+        self.0.iter_mut()
+    }
+
+    /// Returns an iterator over mutable references to the values of the map.
+    pub fn values_mut(&mut self) -> hash_map::ValuesMut<'_, K, V> {
+        self.0.values_mut()
+    }
+}
+
+impl<T: Ord> NonEmptyBinaryHeap<T> {
+    /// Returns the greatest element of the heap.
+    pub fn peek(&self) -> &T {
+        self.0.peek().expect(NON_EMPTY_INVARIANT)
+    }
+
+    /// Pushes an element onto the heap.
+    pub fn push(&mut self, value: T) {
+        self.0.push(value)
+    }
+
+    /// Removes the greatest element of the heap and returns it, along with the remaining heap.
+    ///
+    /// Since the greatest element may be the last one, the heap is consumed, and only given back
+    /// if it is still non-empty.
+    ///
+    /// # Returns
+    ///
+    /// - `(T, Some(Self))` if the heap contained more than one element.
+    /// - `(T, None)` if the returned element was the last one, i.e. the heap would become empty.
+    pub fn pop(self) -> (T, Option<Self>) {
+        let mut heap = self.0;
+        let value = heap.pop().expect(NON_EMPTY_INVARIANT);
+        (value, (!heap.is_empty()).then_some(Self(heap)))
+    }
+
+    /// Consumes the heap and returns a non-empty vector of its elements in ascending order.
+    pub fn into_sorted_vec(self) -> NonEmptyVec<T> {
+        BoundedCollection(self.0.into_sorted_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Note: The use of macros in this test module may seem excessive, and I apologize for it.
+    // However, it allows us to reduce boilerplate code and ensure consistency across multiple tests
+    // for different non-empty collection types. The use of `cargo expand` can be helpful to inspect
+    // the expanded macro-generated code for better understanding and debugging.
+
+    use super::*;
+    #[cfg(feature = "serde")]
+    use serde_json;
+
+    #[test]
+    fn test_nonempty_collection_new() {
+        let data_vec = Vec::from([1, 2, 3]);
+
+        macro_rules! generate_nonempty_collection_new_test {
+            (
+                $collection_name:ident,
+                $collection_type:ty,
+                $empty_collection_name:ident,
+                $nonempty_collection_name:ident,
+                $nonempty_collection_type:ty
+            ) => {
+                let $collection_name: $collection_type = data_vec.clone().into_iter().collect();
+
+                let $nonempty_collection_name = <$nonempty_collection_type>::new($collection_name.clone());
+                assert!($nonempty_collection_name.is_ok());
+
+                let $empty_collection_name: $collection_type = <$collection_type>::new();
+                let $nonempty_collection_name = <$nonempty_collection_type>::new($empty_collection_name);
+                assert!($nonempty_collection_name.is_err());
+            };
+        }
+
+        generate_nonempty_collection_new_test!(
+            btree_set,
+            BTreeSet<i32>,
+            empty_btree_set,
+            nonempty_btree_set,
+            NonEmptyBTreeSet<_>
+        );
+        generate_nonempty_collection_new_test!(
+            hash_set,
+            HashSet<i32>,
+            empty_hash_set,
+            nonempty_hash_set,
+            NonEmptyHashSet<_>
+        );
+        generate_nonempty_collection_new_test!(vec, Vec<i32>, empty_vec, nonempty_vec, NonEmptyVec<_>);
+        generate_nonempty_collection_new_test!(
+            vec_deque,
+            VecDeque<i32>,
+            empty_vec_deque,
+            nonempty_vec_deque,
+            NonEmptyVecDeque<_>
+        );
+        generate_nonempty_collection_new_test!(
+            linked_list,
+            LinkedList<i32>,
+            empty_linked_list,
+            nonempty_linked_list,
+            NonEmptyLinkedList<_>
+        );
+
+        let data_map = HashMap::from([(1, 2), (3, 4), (5, 6)]);
+
+        macro_rules! generate_nonempty_map_new_test {
+            (
+                $collection_name:ident,
+                $collection_type:ty,
+                $empty_collection_name:ident,
+                $nonempty_collection_name:ident,
+                $nonempty_collection_type:ty
+            ) => {
+                let $collection_name: $collection_type = data_map.clone().into_iter().collect();
+
+                let $nonempty_collection_name = <$nonempty_collection_type>::new($collection_name.clone());
+                assert!($nonempty_collection_name.is_ok());
+
+                let $empty_collection_name: $collection_type = <$collection_type>::new();
+                let $nonempty_collection_name = <$nonempty_collection_type>::new($empty_collection_name);
+                assert!($nonempty_collection_name.is_err());
+            };
+        }
+
+        generate_nonempty_map_new_test!(btree_map, BTreeMap<i32, i32>, empty_btree_map, nonempty_btree_map, NonEmptyBTreeMap<_, _>);
+        generate_nonempty_map_new_test!(hash_map, HashMap<i32, i32>, empty_hash_map, nonempty_hash_map, NonEmptyHashMap<_, _>);
+    }
+
+    #[test]
+    fn test_nonempty_collection_new_without_clone() {
+        struct NotClone;
+
+        let nonempty_vec = NonEmptyVec::new(vec![NotClone, NotClone]);
+        assert!(nonempty_vec.is_ok());
+        assert!(NonEmptyVec::<NotClone>::new(Vec::new()).is_err());
+
+        #[derive(Default)]
+        struct Bag(Vec<NotClone>);
+
+        impl IntoIterator for Bag {
+            type Item = NotClone;
+            type IntoIter = std::vec::IntoIter<NotClone>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.0.into_iter()
+            }
+        }
+
+        impl Length for Bag {
+            fn len(&self) -> usize {
+                self.0.len()
+            }
+        }
+
+        let nonempty_bag = NonEmptyCollection::new(Bag(vec![NotClone]));
+        assert_eq!(nonempty_bag.unwrap().len().get(), 1);
+        assert!(NonEmptyCollection::new(Bag::default()).is_err());
+    }
+
+    #[test]
+    fn test_bounded_collection_new() {
+        let line = BoundedVec::<(i32, i32), 2, { usize::MAX }>::new(vec![(0, 0), (1, 1)]);
+        assert!(line.is_ok());
+
+        let point = BoundedVec::<(i32, i32), 2, { usize::MAX }>::new(vec![(0, 0)]);
+        assert!(matches!(
+            point,
+            Err(SequenceContentError::TooFew { min: 2, actual: 1 })
+        ));
+
+        let rgb = BoundedVec::<u8, 3, 3>::new(vec![255, 128, 0]);
+        assert_eq!(rgb.unwrap().into_inner(), vec![255, 128, 0]);
+
+        let rgba = BoundedVec::<u8, 3, 3>::new(vec![255, 128, 0, 64]);
+        assert!(matches!(
+            rgba,
+            Err(SequenceContentError::TooMany { max: 3, actual: 4 })
+        ));
+        assert_eq!(
+            rgba.unwrap_err().to_string(),
+            "sequence has too many elements (maximum: 3, actual: 4)"
+        );
+
+        let tags = BoundedCollection::<_, BTreeSet<&str>, 1, 50>::new(BTreeSet::from(["rust", "serde"]));
+        assert!(tags.is_ok());
+        let tags = BoundedCollection::<_, BTreeSet<&str>, 1, 50>::new(BTreeSet::new());
+        assert!(matches!(tags, Err(SequenceContentError::Empty)));
+
+        let optional = BoundedVec::<i32, 0, 2>::new(Vec::new());
+        assert!(optional.is_ok());
+
+        let pairs: Result<BoundedCollection<_, HashMap<i32, i32>, 2, 2>, _> = HashMap::from([(1, 2)]).try_into();
+        assert!(matches!(
+            pairs,
+            Err(SequenceContentError::TooFew { min: 2, actual: 1 })
+        ));
+        let pairs: BoundedCollection<_, HashMap<i32, i32>, 2, 2> = HashMap::from([(1, 2), (3, 4)]).try_into().unwrap();
+        assert_eq!(HashMap::from(pairs), HashMap::from([(1, 2), (3, 4)]));
+    }
+
+    #[test]
+    fn test_nonempty_collection_from_head_tail() {
+        macro_rules! generate_nonempty_collection_from_head_tail_test {
+            (
+                $collection_type:ty,
+                $nonempty_collection_name:ident,
+                $nonempty_collection_type:ty
+            ) => {
+                let $nonempty_collection_name = <$nonempty_collection_type>::from_head_tail(1, vec![2, 3]);
+                assert_eq!(
+                    $nonempty_collection_name.into_inner(),
+                    <$collection_type>::from([1, 2, 3])
+                );
+
+                let $nonempty_collection_name = <$nonempty_collection_type>::from_head_tail(1, Vec::new());
+                assert_eq!(
+                    $nonempty_collection_name.into_inner(),
+                    <$collection_type>::from([1])
+                );
+
+                let $nonempty_collection_name = <$nonempty_collection_type>::singleton(1);
+                assert_eq!(
+                    $nonempty_collection_name.into_inner(),
+                    <$collection_type>::from([1])
+                );
+            };
+        }
+
+        generate_nonempty_collection_from_head_tail_test!(BTreeSet<i32>, nonempty_btree_set, NonEmptyBTreeSet<i32>);
+        generate_nonempty_collection_from_head_tail_test!(HashSet<i32>, nonempty_hash_set, NonEmptyHashSet<i32>);
+        generate_nonempty_collection_from_head_tail_test!(Vec<i32>, nonempty_vec, NonEmptyVec<i32>);
+        generate_nonempty_collection_from_head_tail_test!(VecDeque<i32>, nonempty_vec_deque, NonEmptyVecDeque<i32>);
+        generate_nonempty_collection_from_head_tail_test!(
+            LinkedList<i32>,
+            nonempty_linked_list,
+            NonEmptyLinkedList<i32>
+        );
+
+        macro_rules! generate_nonempty_map_from_head_tail_test {
+            (
+                $collection_type:ty,
+                $nonempty_collection_name:ident,
+                $nonempty_collection_type:ty
+            ) => {
+                let $nonempty_collection_name = <$nonempty_collection_type>::from_head_tail((1, 2), vec![(3, 4)]);
+                assert_eq!(
+                    $nonempty_collection_name.into_inner(),
+                    <$collection_type>::from([(1, 2), (3, 4)])
+                );
+
+                let $nonempty_collection_name = <$nonempty_collection_type>::from_head_tail((1, 2), vec![(1, 3)]);
+                assert_eq!(
+                    $nonempty_collection_name.into_inner(),
+                    <$collection_type>::from([(1, 3)])
+                );
+
+                let $nonempty_collection_name = <$nonempty_collection_type>::singleton((1, 2));
+                assert_eq!(
+                    $nonempty_collection_name.into_inner(),
+                    <$collection_type>::from([(1, 2)])
+                );
+            };
+        }
+
+        generate_nonempty_map_from_head_tail_test!(BTreeMap<i32, i32>, nonempty_btree_map, NonEmptyBTreeMap<i32, i32>);
+        generate_nonempty_map_from_head_tail_test!(HashMap<i32, i32>, nonempty_hash_map, NonEmptyHashMap<i32, i32>);
+    }
+
+    #[test]
+    fn test_nonempty_collection_conversion() {
+        let data_vec = Vec::from([1, 2, 3]);
+
+        macro_rules! generate_nonempty_collection_conversion_test {
+            (
+                $collection_name:ident,
+                $collection_type:ty,
+                $nonempty_collection_name:ident,
+                $nonempty_collection_type:ty
+            ) => {
+                let $collection_name: $collection_type = data_vec.clone().into_iter().collect();
+
+                let $nonempty_collection_name: $nonempty_collection_type = $collection_name.clone().try_into().unwrap();
+                assert_eq!(
+                    <$collection_type>::from($nonempty_collection_name),
+                    $collection_name
+                );
+
+                let $nonempty_collection_name = <$nonempty_collection_type>::try_from(<$collection_type>::new());
+                assert!(matches!(
+                    $nonempty_collection_name,
+                    Err(SequenceContentError::Empty)
+                ));
+            };
+        }
+
+        generate_nonempty_collection_conversion_test!(
+            btree_set,
+            BTreeSet<i32>,
+            nonempty_btree_set,
+            NonEmptyBTreeSet<i32>
+        );
+        generate_nonempty_collection_conversion_test!(
+            hash_set,
+            HashSet<i32>,
+            nonempty_hash_set,
+            NonEmptyHashSet<i32>
+        );
+        generate_nonempty_collection_conversion_test!(vec, Vec<i32>, nonempty_vec, NonEmptyVec<i32>);
+        generate_nonempty_collection_conversion_test!(
+            vec_deque,
+            VecDeque<i32>,
+            nonempty_vec_deque,
+            NonEmptyVecDeque<i32>
+        );
+        generate_nonempty_collection_conversion_test!(
+            linked_list,
+            LinkedList<i32>,
+            nonempty_linked_list,
+            NonEmptyLinkedList<i32>
+        );
+
+        let data_map = HashMap::from([(1, 2), (3, 4), (5, 6)]);
+
+        let nonempty_hash_map: NonEmptyHashMap<i32, i32> = data_map.clone().try_into().unwrap();
+        let nonempty_btree_map = NonEmptyBTreeMap::from(nonempty_hash_map);
+        assert_eq!(
+            BTreeMap::from(nonempty_btree_map.clone()),
+            data_map.clone().into_iter().collect()
+        );
+        assert_eq!(
+            HashMap::from(NonEmptyHashMap::from(nonempty_btree_map)),
+            data_map
+        );
+        assert!(NonEmptyBTreeMap::<i32, i32>::try_from(BTreeMap::new()).is_err());
+
+        let nonempty_vec: NonEmptyVec<i32> = data_vec.clone().try_into().unwrap();
+        assert_eq!(
+            NonEmptyVecDeque::from(nonempty_vec.clone()).into_inner(),
+            VecDeque::from([1, 2, 3])
+        );
+        assert_eq!(
+            NonEmptyLinkedList::from(nonempty_vec.clone()).into_inner(),
+            LinkedList::from([1, 2, 3])
+        );
+        assert_eq!(
+            NonEmptyBTreeSet::from(nonempty_vec.clone()).into_inner(),
+            BTreeSet::from([1, 2, 3])
+        );
+        assert_eq!(
+            NonEmptyHashSet::from(nonempty_vec.clone()).into_inner(),
+            HashSet::from([1, 2, 3])
+        );
+        assert_eq!(
+            NonEmptyVec::from(NonEmptyVecDeque::from(nonempty_vec.clone())),
+            nonempty_vec
+        );
+        assert_eq!(
+            NonEmptyVec::from(NonEmptyLinkedList::from(nonempty_vec.clone())),
+            nonempty_vec
+        );
+        assert_eq!(
+            NonEmptyVec::from(NonEmptyBTreeSet::from(nonempty_vec.clone())),
+            nonempty_vec
+        );
+        assert_eq!(
+            NonEmptyVec::from(NonEmptyHashSet::from(nonempty_vec))
+                .len()
+                .get(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_nonempty_collection_conversion_with_question_mark() {
+        fn parse(data: Vec<i32>) -> Result<NonEmptyVec<i32>, Box<dyn std::error::Error>> {
+            Ok(data.try_into()?)
+        }
+
+        assert!(parse(vec![1]).is_ok());
+        assert_eq!(
+            parse(Vec::new()).unwrap_err().to_string(),
+            "sequence is empty"
+        );
+    }
+
+    #[test]
+    fn test_nonempty_collection_macros() {
+        let nonempty_vec: NonEmptyVec<i32> = non_empty_vec![1, 2, 3,];
+        assert_eq!(nonempty_vec.into_inner(), vec![1, 2, 3]);
+
+        let nonempty_hash_set: NonEmptyHashSet<i32> = non_empty_set![1, 2, 2];
+        assert_eq!(nonempty_hash_set.into_inner(), HashSet::from([1, 2]));
+
+        let nonempty_btree_set: NonEmptyBTreeSet<i32> = non_empty_btree_set![2, 1];
+        assert_eq!(nonempty_btree_set.into_inner(), BTreeSet::from([1, 2]));
+
+        let nonempty_hash_map: NonEmptyHashMap<i32, i32> = non_empty_map! { 1 => 2, 3 => 4 };
+        assert_eq!(
+            nonempty_hash_map.into_inner(),
+            HashMap::from([(1, 2), (3, 4)])
+        );
+
+        let nonempty_btree_map: NonEmptyBTreeMap<i32, i32> = non_empty_btree_map! { 3 => 4, 1 => 2, };
+        assert_eq!(
+            nonempty_btree_map.into_inner(),
+            BTreeMap::from([(1, 2), (3, 4)])
+        );
+    }
+
+    #[test]
+    fn test_nonempty_collection_new_unchecked() {
+        let data_vec = Vec::from([1, 2, 3]);
+
+        macro_rules! generate_nonempty_collection_new_unchecked_test {
+            (
+                $collection_name:ident,
+                $collection_type:ty,
+                $nonempty_collection_name:ident,
+                $nonempty_collection_type:ty
+            ) => {
+                let $collection_name: $collection_type = data_vec.clone().into_iter().collect();
+
+                let $nonempty_collection_name = unsafe { <$nonempty_collection_type>::new_unchecked($collection_name.clone()) };
+                assert_eq!($nonempty_collection_name.into_inner(), $collection_name);
+            };
+        }
+
+        generate_nonempty_collection_new_unchecked_test!(
+            btree_set,
+            BTreeSet<i32>,
+            nonempty_btree_set,
+            NonEmptyBTreeSet<_>
+        );
+        generate_nonempty_collection_new_unchecked_test!(
+            hash_set,
+            HashSet<i32>,
+            nonempty_hash_set,
+            NonEmptyHashSet<_>
+        );
+        generate_nonempty_collection_new_unchecked_test!(vec, Vec<i32>, nonempty_vec, NonEmptyVec<_>);
+        generate_nonempty_collection_new_unchecked_test!(
+            vec_deque,
+            VecDeque<i32>,
+            nonempty_vec_deque,
+            NonEmptyVecDeque<_>
+        );
+        generate_nonempty_collection_new_unchecked_test!(
+            linked_list,
+            LinkedList<i32>,
+            nonempty_linked_list,
+            NonEmptyLinkedList<_>
+        );
+
+        let data_map = HashMap::from([(1, 2), (3, 4), (5, 6)]);
+
+        macro_rules! generate_nonempty_map_new_unchecked_test {
+            (
+                $collection_name:ident,
+                $collection_type:ty,
+                $nonempty_collection_name:ident,
+                $nonempty_collection_type:ty
+            ) => {
+                let $collection_name: $collection_type = data_map.clone().into_iter().collect();
+
+                let $nonempty_collection_name = unsafe { <$nonempty_collection_type>::new_unchecked($collection_name.clone()) };
+                assert_eq!($nonempty_collection_name.into_inner(), $collection_name);
+            };
+        }
+
+        generate_nonempty_map_new_unchecked_test!(btree_map, BTreeMap<i32, i32>, nonempty_btree_map, NonEmptyBTreeMap<_, _>);
+        generate_nonempty_map_new_unchecked_test!(hash_map, HashMap<i32, i32>, nonempty_hash_map, NonEmptyHashMap<_, _>);
+    }
+
+    #[test]
+    fn test_nonempty_collection_borrow() {
+        let data_vec = Vec::from([1, 2, 3]);
+
+        macro_rules! generate_nonempty_collection_borrow_test {
+            (
+                $collection_name:ident,
+                $collection_type:ty,
+                $nonempty_collection_name:ident,
+                $nonempty_collection_type:ty
+            ) => {
+                let $collection_name: $collection_type = data_vec.clone().into_iter().collect();
+                let $nonempty_collection_name = <$nonempty_collection_type>::new($collection_name.clone()).unwrap();
+
+                assert_eq!($nonempty_collection_name.as_inner(), &$collection_name);
+                assert_eq!(&*$nonempty_collection_name, &$collection_name);
+                assert_eq!(
+                    AsRef::<$collection_type>::as_ref(&$nonempty_collection_name),
+                    &$collection_name
+                );
+                assert_eq!(
+                    Borrow::<$collection_type>::borrow(&$nonempty_collection_name),
+                    &$collection_name
+                );
+                assert_eq!(
+                    $nonempty_collection_name.len().get(),
+                    $collection_name.len()
+                );
+                assert!((&$nonempty_collection_name)
+                    .into_iter()
+                    .eq($collection_name.iter()));
+            };
+        }
+
+        generate_nonempty_collection_borrow_test!(
+            btree_set,
+            BTreeSet<i32>,
+            nonempty_btree_set,
+            NonEmptyBTreeSet<_>
+        );
+        generate_nonempty_collection_borrow_test!(
+            hash_set,
+            HashSet<i32>,
+            nonempty_hash_set,
+            NonEmptyHashSet<_>
+        );
+        generate_nonempty_collection_borrow_test!(vec, Vec<i32>, nonempty_vec, NonEmptyVec<_>);
+        generate_nonempty_collection_borrow_test!(
+            vec_deque,
+            VecDeque<i32>,
+            nonempty_vec_deque,
+            NonEmptyVecDeque<_>
+        );
+        generate_nonempty_collection_borrow_test!(
+            linked_list,
+            LinkedList<i32>,
+            nonempty_linked_list,
+            NonEmptyLinkedList<_>
+        );
+
+        let data_map = HashMap::from([(1, 2), (3, 4), (5, 6)]);
+
+        macro_rules! generate_nonempty_map_borrow_test {
+            (
+                $collection_name:ident,
+                $collection_type:ty,
+                $nonempty_collection_name:ident,
+                $nonempty_collection_type:ty
+            ) => {
+                let $collection_name: $collection_type = data_map.clone().into_iter().collect();
+                let $nonempty_collection_name = <$nonempty_collection_type>::new($collection_name.clone()).unwrap();
+
+                assert_eq!($nonempty_collection_name.as_inner(), &$collection_name);
+                assert_eq!(&*$nonempty_collection_name, &$collection_name);
+                assert_eq!(
+                    AsRef::<$collection_type>::as_ref(&$nonempty_collection_name),
+                    &$collection_name
+                );
+                assert_eq!(
+                    Borrow::<$collection_type>::borrow(&$nonempty_collection_name),
+                    &$collection_name
+                );
+                assert_eq!($nonempty_collection_name.get(&3), Some(&4));
+                assert!((&$nonempty_collection_name)
+                    .into_iter()
+                    .eq($collection_name.iter()));
+            };
+        }
+
+        generate_nonempty_map_borrow_test!(btree_map, BTreeMap<i32, i32>, nonempty_btree_map, NonEmptyBTreeMap<_, _>);
+        generate_nonempty_map_borrow_test!(hash_map, HashMap<i32, i32>, nonempty_hash_map, NonEmptyHashMap<_, _>);
+    }
+
+    #[test]
+    fn test_nonempty_collection_len() {
+        let data_vec = Vec::from([1, 2, 3]);
+
+        macro_rules! generate_nonempty_collection_len_test {
+            (
+                $nonempty_collection_name:ident,
+                $nonempty_collection_type:ty
+            ) => {
+                let $nonempty_collection_name = <$nonempty_collection_type>::new(data_vec.clone().into_iter().collect()).unwrap();
+                assert_eq!(
+                    $nonempty_collection_name.len(),
+                    NonZeroUsize::new(3).unwrap()
+                );
+                assert_eq!(Length::len($nonempty_collection_name.as_inner()), 3);
+                assert!(!Length::is_empty($nonempty_collection_name.as_inner()));
+                #[allow(deprecated)]
+                let is_empty = $nonempty_collection_name.is_empty();
+                assert!(!is_empty);
+            };
+        }
+
+        generate_nonempty_collection_len_test!(nonempty_btree_set, NonEmptyBTreeSet<i32>);
+        generate_nonempty_collection_len_test!(nonempty_hash_set, NonEmptyHashSet<i32>);
+        generate_nonempty_collection_len_test!(nonempty_vec, NonEmptyVec<i32>);
+        generate_nonempty_collection_len_test!(nonempty_vec_deque, NonEmptyVecDeque<i32>);
+        generate_nonempty_collection_len_test!(nonempty_linked_list, NonEmptyLinkedList<i32>);
+
+        let data_map = HashMap::from([(1, 2), (3, 4), (5, 6)]);
+
+        let nonempty_btree_map = NonEmptyBTreeMap::new(data_map.clone().into_iter().collect()).unwrap();
+        assert_eq!(nonempty_btree_map.len(), NonZeroUsize::new(3).unwrap());
+        let nonempty_hash_map = NonEmptyHashMap::new(data_map).unwrap();
+        assert_eq!(nonempty_hash_map.len(), NonZeroUsize::new(3).unwrap());
+
+        let sum: i32 = nonempty_hash_map.values().sum();
+        assert_eq!(sum / nonempty_hash_map.len().get() as i32, 4);
+    }
+
+    #[test]
+    fn test_nonempty_sequence() {
+        let data_vec = Vec::from([2, 1, 3]);
+
+        macro_rules! generate_nonempty_sequence_test {
+            (
+                $nonempty_collection_name:ident,
+                $nonempty_collection_type:ty,
+                $is_ordered:expr
+            ) => {
+                let $nonempty_collection_name = <$nonempty_collection_type>::new(data_vec.clone().into_iter().collect()).unwrap();
+
+                if $is_ordered {
+                    assert_eq!($nonempty_collection_name.first(), &2);
+                    assert_eq!($nonempty_collection_name.last(), &3);
+                } else {
+                    assert!(data_vec.contains($nonempty_collection_name.first()));
+                    assert!(data_vec.contains($nonempty_collection_name.last()));
+                }
+                assert_eq!($nonempty_collection_name.minimum(), &1);
+                assert_eq!($nonempty_collection_name.maximum(), &3);
+                assert_eq!(
+                    $nonempty_collection_name.clone().reduce(|acc, it| acc + it),
+                    6
+                );
+
+                let mut folded = $nonempty_collection_name.fold1(
+                    |it| vec![it],
+                    |mut acc, it| {
+                        acc.push(it);
+                        acc
+                    },
+                );
+                folded.sort();
+                assert_eq!(folded, vec![1, 2, 3]);
+            };
+        }
+
+        generate_nonempty_sequence_test!(nonempty_btree_set, NonEmptyBTreeSet<i32>, false);
+        generate_nonempty_sequence_test!(nonempty_hash_set, NonEmptyHashSet<i32>, false);
+        generate_nonempty_sequence_test!(nonempty_vec, NonEmptyVec<i32>, true);
+        generate_nonempty_sequence_test!(nonempty_vec_deque, NonEmptyVecDeque<i32>, true);
+        generate_nonempty_sequence_test!(nonempty_linked_list, NonEmptyLinkedList<i32>, true);
+
+        let data_map = HashMap::from([(1, 2), (3, 4), (5, 6)]);
+
+        macro_rules! generate_nonempty_map_sequence_test {
+            (
+                $nonempty_collection_name:ident,
+                $nonempty_collection_type:ty
+            ) => {
+                let $nonempty_collection_name = <$nonempty_collection_type>::new(data_map.clone().into_iter().collect()).unwrap();
+
+                let (first_key, first_value) = $nonempty_collection_name.first();
+                assert_eq!(data_map.get(first_key), Some(first_value));
+                let (last_key, last_value) = $nonempty_collection_name.last();
+                assert_eq!(data_map.get(last_key), Some(last_value));
+                assert_eq!($nonempty_collection_name.minimum(), (&1, &2));
+                assert_eq!($nonempty_collection_name.maximum(), (&5, &6));
+                assert_eq!(
+                    $nonempty_collection_name
+                        .clone()
+                        .reduce(|(k1, v1), (k2, v2)| (k1 + k2, v1 + v2)),
+                    (9, 12)
+                );
+                assert_eq!(
+                    $nonempty_collection_name.fold1(|(_, v)| v, |acc, (_, v)| acc + v),
+                    12
+                );
+            };
+        }
+
+        generate_nonempty_map_sequence_test!(nonempty_btree_map, NonEmptyBTreeMap<i32, i32>);
+        generate_nonempty_map_sequence_test!(nonempty_hash_map, NonEmptyHashMap<i32, i32>);
+
+        let nonempty_btree_map = NonEmptyBTreeMap::new(data_map.clone().into_iter().collect()).unwrap();
+        assert_eq!(nonempty_btree_map.first(), (&1, &2));
+        assert_eq!(nonempty_btree_map.last(), (&5, &6));
+    }
+
+    #[test]
+    fn test_nonempty_vec_split_first() {
+        let nonempty_vec = NonEmptyVec::new(vec![1, 2, 3]).unwrap();
+        assert_eq!(nonempty_vec.split_first(), (&1, &[2, 3][..]));
+
+        let singleton_vec = NonEmptyVec::new(vec![1]).unwrap();
+        assert_eq!(singleton_vec.split_first(), (&1, &[][..]));
+    }
+
+    #[test]
+    fn test_nonempty_collection_extend() {
+        let mut nonempty_vec = NonEmptyVec::new(vec![1]).unwrap();
+        nonempty_vec.extend([2, 3]);
+        assert_eq!(nonempty_vec.as_inner(), &vec![1, 2, 3]);
+
+        let mut nonempty_hash_map = NonEmptyHashMap::new(HashMap::from([(1, 2)])).unwrap();
+        nonempty_hash_map.extend([(3, 4)]);
+        assert_eq!(
+            nonempty_hash_map.as_inner(),
+            &HashMap::from([(1, 2), (3, 4)])
+        );
+    }
+
+    #[test]
+    fn test_nonempty_vec_adaptors() {
+        let nonempty_vec = NonEmptyVec::new(vec![1, 2, 3]).unwrap();
+
+        assert_eq!(
+            nonempty_vec.clone().map(|it| it * 2).into_inner(),
+            vec![2, 4, 6]
+        );
+        assert_eq!(
+            nonempty_vec
+                .clone()
+                .zip(NonEmptyVec::new(vec!['a', 'b']).unwrap())
+                .into_inner(),
+            vec![(1, 'a'), (2, 'b')]
+        );
+        assert_eq!(
+            nonempty_vec.clone().enumerate().into_inner(),
+            vec![(0, 1), (1, 2), (2, 3)]
+        );
+        assert_eq!(
+            nonempty_vec
+                .flat_map(|it| NonEmptyVec::new(vec![it; it]).unwrap())
+                .into_inner(),
+            vec![1, 2, 2, 3, 3, 3]
+        );
+    }
+
+    #[test]
+    fn test_nonempty_vec_mutation() {
+        let mut nonempty_vec = NonEmptyVec::new(vec![1, 2, 3]).unwrap();
+
+        nonempty_vec.push(4);
+        nonempty_vec.insert(0, 0);
+        assert_eq!(nonempty_vec.as_inner(), &vec![0, 1, 2, 3, 4]);
+
+        *nonempty_vec.get_mut(0).unwrap() = 10;
+        assert!(nonempty_vec.get_mut(5).is_none());
+        nonempty_vec.iter_mut().for_each(|it| *it += 1);
+        assert_eq!(nonempty_vec.as_inner(), &vec![11, 2, 3, 4, 5]);
+
+        nonempty_vec.truncate(NonZeroUsize::new(3).unwrap());
+        assert_eq!(nonempty_vec.as_inner(), &vec![11, 2, 3]);
+
+        let mut calls = 0;
+        assert!(nonempty_vec
+            .retain(|it| {
+                calls += 1;
+                *it < 10
+            })
+            .is_ok());
+        assert_eq!(calls, 3);
+        assert_eq!(nonempty_vec.as_inner(), &vec![2, 3]);
+
+        assert!(nonempty_vec.retain(|it| *it > 10).is_err());
+        assert_eq!(nonempty_vec.as_inner(), &vec![2, 3]);
+
+        assert_eq!(nonempty_vec.pop(), Some(3));
+        assert_eq!(nonempty_vec.pop(), None);
+        assert_eq!(nonempty_vec.as_inner(), &vec![2]);
+    }
+
+    #[test]
+    fn test_nonempty_vec_deque_mutation() {
+        let mut nonempty_vec_deque = NonEmptyVecDeque::new(VecDeque::from([1, 2, 3])).unwrap();
+
+        nonempty_vec_deque.push_back(4);
+        nonempty_vec_deque.push_front(0);
+        nonempty_vec_deque.insert(1, 5);
+        assert_eq!(
+            nonempty_vec_deque.as_inner(),
+            &VecDeque::from([0, 5, 1, 2, 3, 4])
+        );
+
+        *nonempty_vec_deque.get_mut(0).unwrap() = 10;
+        nonempty_vec_deque.iter_mut().for_each(|it| *it += 1);
+        assert_eq!(
+            nonempty_vec_deque.as_inner(),
+            &VecDeque::from([11, 6, 2, 3, 4, 5])
+        );
+
+        nonempty_vec_deque.truncate(NonZeroUsize::new(4).unwrap());
+        assert!(nonempty_vec_deque.retain(|it| *it < 10).is_ok());
+        assert_eq!(nonempty_vec_deque.as_inner(), &VecDeque::from([6, 2, 3]));
+        assert!(nonempty_vec_deque.retain(|it| *it > 10).is_err());
+        assert_eq!(nonempty_vec_deque.as_inner(), &VecDeque::from([6, 2, 3]));
+
+        assert_eq!(nonempty_vec_deque.pop_front(), Some(6));
+        assert_eq!(nonempty_vec_deque.pop_back(), Some(3));
+        assert_eq!(nonempty_vec_deque.pop_back(), None);
+        assert_eq!(nonempty_vec_deque.pop_front(), None);
+        assert_eq!(nonempty_vec_deque.as_inner(), &VecDeque::from([2]));
+    }
+
+    #[test]
+    fn test_nonempty_linked_list_mutation() {
+        let mut nonempty_linked_list = NonEmptyLinkedList::new(LinkedList::from([1, 2, 3])).unwrap();
+
+        nonempty_linked_list.push_back(4);
+        nonempty_linked_list.push_front(0);
+        *nonempty_linked_list.front_mut() = 10;
+        *nonempty_linked_list.back_mut() = 40;
+        nonempty_linked_list.iter_mut().for_each(|it| *it += 1);
+        assert_eq!(
+            nonempty_linked_list.as_inner(),
+            &LinkedList::from([11, 2, 3, 4, 41])
+        );
+
+        nonempty_linked_list.truncate(NonZeroUsize::new(4).unwrap());
+        assert!(nonempty_linked_list.retain(|it| *it < 10).is_ok());
+        assert_eq!(
+            nonempty_linked_list.as_inner(),
+            &LinkedList::from([2, 3, 4])
+        );
+        assert!(nonempty_linked_list.retain(|it| *it > 10).is_err());
+        assert_eq!(
+            nonempty_linked_list.as_inner(),
+            &LinkedList::from([2, 3, 4])
+        );
+
+        assert_eq!(nonempty_linked_list.pop_front(), Some(2));
+        assert_eq!(nonempty_linked_list.pop_back(), Some(4));
+        assert_eq!(nonempty_linked_list.pop_back(), None);
+        assert_eq!(nonempty_linked_list.pop_front(), None);
+        assert_eq!(nonempty_linked_list.as_inner(), &LinkedList::from([3]));
+    }
+
+    #[test]
+    fn test_nonempty_set_mutation() {
+        let data_vec = Vec::from([1, 2, 3]);
+
+        macro_rules! generate_nonempty_set_mutation_test {
+            (
+                $collection_type:ty,
+                $nonempty_collection_name:ident,
+                $nonempty_collection_type:ty
+            ) => {
+                let mut $nonempty_collection_name = <$nonempty_collection_type>::new(data_vec.clone().into_iter().collect()).unwrap();
+
+                assert!($nonempty_collection_name.insert(4));
+                assert!(!$nonempty_collection_name.insert(4));
+                assert!(matches!($nonempty_collection_name.remove(&4), Ok(true)));
+                assert!(matches!($nonempty_collection_name.remove(&4), Ok(false)));
+
+                assert!($nonempty_collection_name.retain(|it| *it > 1).is_ok());
+                assert_eq!(
+                    $nonempty_collection_name.as_inner(),
+                    &<$collection_type>::from([2, 3])
+                );
+                assert!($nonempty_collection_name.retain(|it| *it > 10).is_err());
+                assert_eq!(
+                    $nonempty_collection_name.as_inner(),
+                    &<$collection_type>::from([2, 3])
+                );
+
+                assert!(matches!($nonempty_collection_name.remove(&2), Ok(true)));
+                assert!($nonempty_collection_name.remove(&3).is_err());
+                assert!(matches!($nonempty_collection_name.remove(&2), Ok(false)));
+                assert_eq!(
+                    $nonempty_collection_name.as_inner(),
+                    &<$collection_type>::from([3])
+                );
+            };
+        }
+
+        generate_nonempty_set_mutation_test!(BTreeSet<i32>, nonempty_btree_set, NonEmptyBTreeSet<i32>);
+        generate_nonempty_set_mutation_test!(HashSet<i32>, nonempty_hash_set, NonEmptyHashSet<i32>);
+
+        let mut nonempty_btree_set = NonEmptyBTreeSet::new(BTreeSet::from([1, 2, 3])).unwrap();
+        assert_eq!(nonempty_btree_set.pop_first(), Some(1));
+        assert_eq!(nonempty_btree_set.pop_last(), Some(3));
+        assert_eq!(nonempty_btree_set.pop_last(), None);
+        assert_eq!(nonempty_btree_set.pop_first(), None);
+        assert_eq!(nonempty_btree_set.as_inner(), &BTreeSet::from([2]));
+    }
+
+    #[test]
+    fn test_nonempty_map_mutation() {
+        let data_map = HashMap::from([(1, 2), (3, 4), (5, 6)]);
+
+        macro_rules! generate_nonempty_map_mutation_test {
+            (
+                $collection_type:ty,
+                $nonempty_collection_name:ident,
+                $nonempty_collection_type:ty
+            ) => {
+                let mut $nonempty_collection_name = <$nonempty_collection_type>::new(data_map.clone().into_iter().collect()).unwrap();
+
+                assert_eq!($nonempty_collection_name.insert(7, 8), None);
+                assert_eq!($nonempty_collection_name.insert(7, 9), Some(8));
+                assert!(matches!($nonempty_collection_name.remove(&7), Ok(Some(9))));
+                assert!(matches!($nonempty_collection_name.remove(&7), Ok(None)));
+
+                *$nonempty_collection_name.get_mut(&1).unwrap() = 20;
+                $nonempty_collection_name
+                    .values_mut()
+                    .for_each(|it| *it += 1);
+                $nonempty_collection_name
+                    .iter_mut()
+                    .for_each(|(_, it)| *it += 1);
+                assert_eq!(
+                    $nonempty_collection_name.as_inner(),
+                    &<$collection_type>::from([(1, 22), (3, 6), (5, 8)])
+                );
+
+                assert!($nonempty_collection_name.retain(|key, _| *key > 1).is_ok());
+                assert_eq!(
+                    $nonempty_collection_name.as_inner(),
+                    &<$collection_type>::from([(3, 6), (5, 8)])
+                );
+                assert!($nonempty_collection_name
+                    .retain(|key, _| *key > 10)
+                    .is_err());
+                assert_eq!(
+                    $nonempty_collection_name.as_inner(),
+                    &<$collection_type>::from([(3, 6), (5, 8)])
+                );
+
+                assert!(matches!($nonempty_collection_name.remove(&3), Ok(Some(6))));
+                assert!($nonempty_collection_name.remove(&5).is_err());
+                assert_eq!(
+                    $nonempty_collection_name.as_inner(),
+                    &<$collection_type>::from([(5, 8)])
+                );
+            };
+        }
+
+        generate_nonempty_map_mutation_test!(BTreeMap<i32, i32>, nonempty_btree_map, NonEmptyBTreeMap<i32, i32>);
+        generate_nonempty_map_mutation_test!(HashMap<i32, i32>, nonempty_hash_map, NonEmptyHashMap<i32, i32>);
+
+        let mut nonempty_btree_map = NonEmptyBTreeMap::new(BTreeMap::from([(1, 2), (3, 4), (5, 6)])).unwrap();
+        assert_eq!(nonempty_btree_map.pop_first(), Some((1, 2)));
+        assert_eq!(nonempty_btree_map.pop_last(), Some((5, 6)));
+        assert_eq!(nonempty_btree_map.pop_last(), None);
+        assert_eq!(nonempty_btree_map.pop_first(), None);
+        assert_eq!(nonempty_btree_map.as_inner(), &BTreeMap::from([(3, 4)]));
+    }
+
+    #[test]
+    fn test_nonempty_binary_heap() {
+        let mut nonempty_binary_heap = NonEmptyBinaryHeap::new(BinaryHeap::from([3, 1, 2])).unwrap();
+        assert_eq!(nonempty_binary_heap.peek(), &3);
+
+        nonempty_binary_heap.push(5);
+        assert_eq!(nonempty_binary_heap.peek(), &5);
+        assert_eq!(nonempty_binary_heap.len().get(), 4);
+
+        let (greatest, rest) = nonempty_binary_heap.pop();
+        assert_eq!(greatest, 5);
+        let nonempty_binary_heap = rest.unwrap();
+        assert_eq!(nonempty_binary_heap.peek(), &3);
+        assert_eq!(
+            nonempty_binary_heap.clone().into_sorted_vec().into_inner(),
+            vec![1, 2, 3]
+        );
+
+        let (last, rest) = NonEmptyBinaryHeap::singleton(1).pop();
+        assert_eq!(last, 1);
+        assert!(rest.is_none());
+
+        let nonempty_vec: NonEmptyVec<i32> = nonempty_binary_heap.into();
+        assert_eq!(nonempty_vec.len().get(), 3);
+        let nonempty_binary_heap: NonEmptyBinaryHeap<i32> = nonempty_vec.into();
+        assert_eq!(nonempty_binary_heap.peek(), &3);
+        assert_eq!(
+            BinaryHeap::from(nonempty_binary_heap).into_sorted_vec(),
+            vec![1, 2, 3]
+        );
+
+        assert!(matches!(
+            NonEmptyBinaryHeap::<i32>::new(BinaryHeap::new()),
+            Err(SequenceContentError::Empty)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_nonempty_binary_heap_serde() {
+        use crate::stdx::collections::sorted_serde::Sorted;
+
+        let nonempty_binary_heap = NonEmptyBinaryHeap::new(BinaryHeap::from([2, 3, 1])).unwrap();
+
+        let serialized = serde_json::to_string(&nonempty_binary_heap).unwrap();
+        let deserialized: NonEmptyBinaryHeap<i32> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.peek(), &3);
+        assert_eq!(
+            deserialized.into_sorted_vec(),
+            nonempty_binary_heap.clone().into_sorted_vec()
+        );
+
+        let serialized = serde_json::to_string(&Sorted(&nonempty_binary_heap)).unwrap();
+        assert_eq!(serialized, "[1,2,3]");
+
+        let deserialized: Result<NonEmptyBinaryHeap<i32>, _> = serde_json::from_str("[]");
+        assert!(deserialized.is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_nonempty_collection_serde() {
+        use crate::stdx::collections::sorted_serde::{Sorted, SortedSerialize};
+
+        fn to_json<T: Serialize>(value: &T) -> String {
+            serde_json::to_string(value).unwrap()
+        }
+
+        fn to_sorted_json<T: SortedSerialize>(value: &T) -> String {
+            serde_json::to_string(&Sorted(value)).unwrap()
+        }
+
+        let data_vec = vec![1, 2, 3];
+
+        macro_rules! generate_nonempty_collection_serde_test {
+            (
+                $collection_name:ident,
+                $collection_type:ty,
+                $nonempty_collection_name:ident,
+                $nonempty_collection_type:ty,
+
+                $serialized_name:ident,
+                $deserialized_name:ident,
+                $invalid_serialized_name:ident,
+                $deserialized_result_name:ident,
+
+                $to_json_string:ident,
+                $expected_json_string:expr
+            ) => {
+                let $collection_name: $collection_type = data_vec.clone().into_iter().collect();
+                let $nonempty_collection_name = <$nonempty_collection_type>::new($collection_name.clone()).unwrap();
+
+                let $serialized_name = $to_json_string(&$nonempty_collection_name);
+                assert_eq!($serialized_name, $expected_json_string);
+
+                let $deserialized_name: $nonempty_collection_type = serde_json::from_str(&$serialized_name).unwrap();
+                assert_eq!($deserialized_name, $nonempty_collection_name);
+
+                let $invalid_serialized_name = "[]";
+                let $deserialized_result_name: Result<$nonempty_collection_type, _> = serde_json::from_str($invalid_serialized_name);
+                assert!($deserialized_result_name.is_err());
+            };
+        }
+
+        generate_nonempty_collection_serde_test!(
+            btree_set,
+            BTreeSet<i32>,
+            nonempty_btree_set,
+            NonEmptyBTreeSet<i32>,
+            btree_set_serialized,
+            btree_set_deserialized,
+            btree_set_invalid_serialized,
+            btree_set_deserialized_result,
+            to_json,
+            "[1,2,3]"
+        );
+        generate_nonempty_collection_serde_test!(
+            hash_set,
+            HashSet<i32>,
+            nonempty_hash_set,
+            NonEmptyHashSet<i32>,
+            hash_set_serialized,
+            hash_set_deserialized,
+            hash_set_invalid_serialized,
+            hash_set_deserialized_result,
+            to_sorted_json,
+            "[1,2,3]"
+        );
+        generate_nonempty_collection_serde_test!(
+            vec,
+            Vec<i32>,
+            nonempty_vec,
+            NonEmptyVec<i32>,
+            vec_serialized,
+            vec_deserialized,
+            vec_invalid_serialized,
+            vec_deserialized_result,
+            to_json,
+            "[1,2,3]"
+        );
+        generate_nonempty_collection_serde_test!(
+            vec_deque,
+            VecDeque<i32>,
+            nonempty_vec_deque,
+            NonEmptyVecDeque<i32>,
+            vec_deque_serialized,
+            vec_deque_deserialized,
+            vec_deque_invalid_serialized,
+            vec_deque_deserialized_result,
+            to_json,
+            "[1,2,3]"
+        );
+        generate_nonempty_collection_serde_test!(
+            linked_list,
+            LinkedList<i32>,
+            nonempty_linked_list,
+            NonEmptyLinkedList<i32>,
+            linked_list_serialized,
+            linked_list_deserialized,
+            linked_list_invalid_serialized,
+            linked_list_deserialized_result,
+            to_json,
+            "[1,2,3]"
+        );
+
+        let data_map = HashMap::from([
+            ("1".to_string(), 2),
+            ("3".to_string(), 4),
+            ("5".to_string(), 6),
+        ]);
+
+        macro_rules! generate_nonempty_map_serde_test {
+            (
+                $collection_name:ident,
+                $collection_type:ty,
+                $nonempty_collection_name:ident,
+                $nonempty_collection_type:ty,
+
+                $serialized_name:ident,
+                $deserialized_name:ident,
+                $invalid_serialized_name:ident,
+                $deserialized_result_name:ident,
+
+                $to_json_string:ident,
+                $expected_json_string:expr
+            ) => {
+                let $collection_name: $collection_type = data_map.clone().into_iter().collect();
+                let $nonempty_collection_name = <$nonempty_collection_type>::new($collection_name.clone()).unwrap();
+
+                let $serialized_name = $to_json_string(&$nonempty_collection_name);
+                assert_eq!($serialized_name, $expected_json_string);
+
+                let $deserialized_name: $nonempty_collection_type = serde_json::from_str(&$serialized_name).unwrap();
+                assert_eq!($deserialized_name, $nonempty_collection_name);
+
+                let $invalid_serialized_name = "{}";
+                let $deserialized_result_name: Result<$nonempty_collection_type, _> = serde_json::from_str($invalid_serialized_name);
+                assert!($deserialized_result_name.is_err());
+            };
+        }
+
+        generate_nonempty_map_serde_test!(btree_map, BTreeMap<String, i32>, nonempty_btree_map, NonEmptyBTreeMap<String, i32>, btree_map_serialized, btree_map_deserialized, btree_map_invalid_serialized, btree_map_deserialized_result, to_json, r#"{"1":2,"3":4,"5":6}"#);
+        generate_nonempty_map_serde_test!(hash_map, HashMap<String, i32>, nonempty_hash_map, NonEmptyHashMap<String, i32>, hash_map_serialized, hash_map_deserialized, hash_map_invalid_serialized, hash_map_deserialized_result, to_sorted_json, r#"{"1":2,"3":4,"5":6}"#);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_bounded_collection_serde() {
+        let rgb: BoundedVec<u8, 3, 3> = serde_json::from_str("[255,128,0]").unwrap();
+        assert_eq!(serde_json::to_string(&rgb).unwrap(), "[255,128,0]");
+
+        let too_few: Result<BoundedVec<u8, 3, 3>, _> = serde_json::from_str("[255,128]");
+        assert!(too_few
+            .unwrap_err()
+            .to_string()
+            .contains("too few elements"));
+
+        let too_many: Result<BoundedVec<u8, 3, 3>, _> = serde_json::from_str("[255,128,0,64]");
+        assert!(too_many
+            .unwrap_err()
+            .to_string()
+            .contains("too many elements"));
+    }
+}
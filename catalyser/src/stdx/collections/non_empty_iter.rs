@@ -0,0 +1,248 @@
+//! This module defines `NonEmptyIter`, an iterator wrapper known to yield at least one element, and
+//! the `IntoNonEmptyIterator` trait for the types that can be turned into one.
+//!
+//! The adaptors that cannot reduce the number of elements to zero (`map`, `zip`, `enumerate` or
+//! `flat_map` over non-empty inner collections) keep the guarantee, so the result of a pipeline can
+//! be collected into another non-empty collection without re-validation.
+//!
+//! `NonEmptyIter` deliberately does not implement `Iterator`, as calling `next` through a mutable
+//! reference could exhaust it without changing its type. It implements `IntoIterator` instead, so
+//! it can be handed to any code expecting a regular iterator.
+//!
+//! # Usage Example
+//!
+//! ```rust
+//! use catalyser::stdx::collections::{NonEmptyBTreeSet, NonEmptyVec};
+//!
+//! let non_empty_vec = NonEmptyVec::new(vec![3, 1, 2]).unwrap();
+//! let non_empty_set: NonEmptyBTreeSet<(usize, i32)> = non_empty_vec
+//!     .iter()
+//!     .map(|it| it * 10)
+//!     .enumerate()
+//!     .collect_non_empty();
+//! assert_eq!(non_empty_set.into_inner().into_iter().collect::<Vec<_>>(), vec![(0, 30), (1, 10), (2, 20)]);
+//! ```
+
+use crate::stdx::collections::{BoundedCollection, GrowableCollection, NonEmptyCollection, NON_EMPTY_INVARIANT};
+use std::iter::{Enumerate, FlatMap, Map, Zip};
+
+/// An iterator wrapper that is known to yield at least one element.
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct NonEmptyIter<I>(I)
+where
+    I: Iterator;
+
+impl<I> NonEmptyIter<I>
+where
+    I: Iterator,
+{
+    /// Creates a new non-empty iterator.
+    ///
+    /// # Parameters
+    ///
+    /// - `iter`: The input iterator.
+    ///
+    /// # Returns
+    ///
+    /// - `Self`: A new non-empty iterator.
+    ///
+    /// # Safety
+    ///
+    /// This function assumes that the iterator yields at least one element.
+    pub unsafe fn new_unchecked(iter: I) -> Self {
+        Self(iter)
+    }
+
+    /// Returns the inner iterator.
+    pub fn into_inner(self) -> I {
+        self.0
+    }
+
+    /// Returns the first element and the iterator over the remaining ones.
+    ///
+    /// # Returns
+    ///
+    /// - `(I::Item, I)`: The first element and the (possibly empty) iterator of the remaining
+    ///   elements.
+    pub fn split_first(mut self) -> (I::Item, I) {
+        let first = self.0.next().expect(NON_EMPTY_INVARIANT);
+        (first, self.0)
+    }
+
+    /// Transforms each element with `f`.
+    pub fn map<B, F>(self, f: F) -> NonEmptyIter<Map<I, F>>
+    where
+        F: FnMut(I::Item) -> B,
+    {
+        NonEmptyIter(self.0.map(f))
+    }
+
+    /// Pairs each element with the element at the same position of `other`.
+    ///
+    /// The resulting iterator stops as soon as one of the two iterators is exhausted. Since both
+    /// yield at least one element, so does the result.
+    pub fn zip<U>(self, other: U) -> NonEmptyIter<Zip<I, U::IntoIter>>
+    where
+        U: IntoNonEmptyIterator,
+    {
+        NonEmptyIter(self.0.zip(other.into_non_empty_iter().0))
+    }
+
+    /// Pairs each element with its index, starting from `0`.
+    pub fn enumerate(self) -> NonEmptyIter<Enumerate<I>> {
+        NonEmptyIter(self.0.enumerate())
+    }
+
+    /// Transforms each element into a non-empty iterable with `f` and flattens the result.
+    pub fn flat_map<U, F>(self, f: F) -> NonEmptyIter<FlatMap<I, U, F>>
+    where
+        U: IntoNonEmptyIterator,
+        F: FnMut(I::Item) -> U,
+    {
+        NonEmptyIter(self.0.flat_map(f))
+    }
+
+    /// Collects the elements into a non-empty collection, without re-validating it.
+    ///
+    /// Only the collections implementing [GrowableCollection] are trusted to hold every collected
+    /// element, so that the result is known to be non-empty.
+    ///
+    /// # Returns
+    ///
+    /// - `NonEmptyCollection<I::Item, C>`: The non-empty collection holding the elements.
+    ///
+    /// # Examples
+    ///
+    /// A collection that may drop the collected elements is rejected:
+    ///
+    /// ```compile_fail
+    /// use catalyser::stdx::collections::{non_empty_iter::IntoNonEmptyIterator, NonEmptyCollection, NonEmptyVec};
+    ///
+    /// #[derive(Default)]
+    /// struct Evens(Vec<i32>);
+    ///
+    /// impl FromIterator<i32> for Evens {
+    ///     fn from_iter<I: IntoIterator<Item = i32>>(iter: I) -> Self {
+    ///         Evens(iter.into_iter().filter(|it| it % 2 == 0).collect())
+    ///     }
+    /// }
+    ///
+    /// impl IntoIterator for Evens {
+    ///     type Item = i32;
+    ///     type IntoIter = std::vec::IntoIter<i32>;
+    ///
+    ///     fn into_iter(self) -> Self::IntoIter {
+    ///         self.0.into_iter()
+    ///     }
+    /// }
+    ///
+    /// let evens: NonEmptyCollection<i32, Evens> = NonEmptyVec::singleton(1).into_non_empty_iter().collect_non_empty();
+    /// ```
+    pub fn collect_non_empty<C>(self) -> NonEmptyCollection<I::Item, C>
+    where
+        C: IntoIterator<Item = I::Item> + Default + FromIterator<I::Item> + GrowableCollection,
+    {
+        BoundedCollection(self.0.collect())
+    }
+}
+
+impl<I> IntoIterator for NonEmptyIter<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+    type IntoIter = I;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+}
+
+/// A trait for the types that can be converted into a [NonEmptyIter].
+pub trait IntoNonEmptyIterator: IntoIterator {
+    /// Converts the value into a non-empty iterator.
+    fn into_non_empty_iter(self) -> NonEmptyIter<Self::IntoIter>;
+}
+
+impl<I> IntoNonEmptyIterator for NonEmptyIter<I>
+where
+    I: Iterator,
+{
+    fn into_non_empty_iter(self) -> NonEmptyIter<Self::IntoIter> {
+        self
+    }
+}
+
+impl<T, C> IntoNonEmptyIterator for NonEmptyCollection<T, C>
+where
+    C: IntoIterator<Item = T> + Default,
+{
+    fn into_non_empty_iter(self) -> NonEmptyIter<Self::IntoIter> {
+        NonEmptyIter(self.0.into_iter())
+    }
+}
+
+impl<'a, T, C> IntoNonEmptyIterator for &'a NonEmptyCollection<T, C>
+where
+    C: IntoIterator<Item = T> + Default,
+    &'a C: IntoIterator,
+{
+    fn into_non_empty_iter(self) -> NonEmptyIter<Self::IntoIter> {
+        NonEmptyIter((&self.0).into_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stdx::collections::{NonEmptyBTreeMap, NonEmptyHashSet, NonEmptyVec};
+    use std::collections::{BTreeMap, HashSet};
+
+    #[test]
+    fn test_non_empty_iter_split_first() {
+        let non_empty_vec = NonEmptyVec::new(vec![1, 2, 3]).unwrap();
+
+        let (first, rest) = non_empty_vec.iter().split_first();
+        assert_eq!(first, &1);
+        assert_eq!(rest.collect::<Vec<_>>(), vec![&2, &3]);
+    }
+
+    #[test]
+    fn test_non_empty_iter_adaptors() {
+        let non_empty_vec = NonEmptyVec::new(vec![1, 2, 3]).unwrap();
+        let other_vec = NonEmptyVec::new(vec!['a', 'b']).unwrap();
+
+        let mapped: NonEmptyVec<i32> = non_empty_vec.iter().map(|it| it * 2).collect_non_empty();
+        assert_eq!(mapped.into_inner(), vec![2, 4, 6]);
+
+        let zipped: NonEmptyVec<(&i32, char)> = non_empty_vec.iter().zip(other_vec).collect_non_empty();
+        assert_eq!(zipped.into_inner(), vec![(&1, 'a'), (&2, 'b')]);
+
+        let enumerated: NonEmptyBTreeMap<usize, &i32> = non_empty_vec.iter().enumerate().collect_non_empty();
+        assert_eq!(
+            enumerated.into_inner(),
+            BTreeMap::from([(0, &1), (1, &2), (2, &3)])
+        );
+
+        let flattened: NonEmptyHashSet<i32> = non_empty_vec
+            .iter()
+            .flat_map(|it| NonEmptyVec::new(vec![*it, it * 10]).unwrap())
+            .collect_non_empty();
+        assert_eq!(flattened.into_inner(), HashSet::from([1, 10, 2, 20, 3, 30]));
+    }
+
+    #[test]
+    fn test_non_empty_iter_into_iterator() {
+        let non_empty_vec = NonEmptyVec::new(vec![1, 2, 3]).unwrap();
+
+        let sum: i32 = non_empty_vec.iter().into_iter().sum();
+        assert_eq!(sum, 6);
+
+        let mut collected = Vec::new();
+        for it in non_empty_vec.into_non_empty_iter().map(|it| it + 1) {
+            collected.push(it);
+        }
+        assert_eq!(collected, vec![2, 3, 4]);
+    }
+}
@@ -0,0 +1,407 @@
+//! This module defines `NonEmptySlice`, a borrowed view over a slice that is known to contain at
+//! least one element.
+//!
+//! `NonEmptySlice` is to `NonEmptyVec` what `[T]` is to `Vec<T>`: it lets APIs take validated data
+//! by reference, without allocation, while keeping the infallible accessors of non-empty
+//! collections.
+//!
+//! # Usage Example
+//!
+//! ```rust
+//! use catalyser::stdx::collections::{non_empty_slice::NonEmptySlice, NonEmptyVec};
+//!
+//! fn average(values: &NonEmptySlice<f64>) -> f64 {
+//!     values.iter().into_iter().sum::<f64>() / values.len().get() as f64
+//! }
+//!
+//! let non_empty_vec = NonEmptyVec::new(vec![1.0, 2.0, 3.0]).unwrap();
+//! assert_eq!(average(non_empty_vec.as_slice()), 2.0);
+//!
+//! let slice = NonEmptySlice::new(&[4.0, 6.0]).unwrap();
+//! assert_eq!(average(slice), 5.0);
+//! assert_eq!(slice.first(), &4.0);
+//!
+//! assert!(NonEmptySlice::<f64>::new(&[]).is_err());
+//! ```
+
+use crate::stdx::{
+    collections::{
+        non_empty_iter::{IntoNonEmptyIterator, NonEmptyIter},
+        BoundedCollection, NonEmptyVec, NON_EMPTY_INVARIANT,
+    },
+    error::is_empty_sequence::SequenceContentError,
+};
+use std::{borrow::Borrow, iter::FusedIterator, num::NonZeroUsize, ops::Deref, slice};
+
+/// A borrowed slice that is known to contain at least one element.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonEmptySlice<T>([T]);
+
+impl<T> NonEmptySlice<T> {
+    /// Creates a new non-empty slice view.
+    ///
+    /// # Parameters
+    ///
+    /// - `slice`: The input slice.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(&Self)` if the slice is non-empty.
+    /// - `Err(SequenceContentError::Empty)` if the slice is empty.
+    pub fn new(slice: &[T]) -> Result<&Self, SequenceContentError> {
+        if slice.is_empty() {
+            Err(SequenceContentError::Empty)
+        } else {
+            // SAFETY: the slice has just been checked to be non-empty.
+            Ok(unsafe { Self::new_unchecked(slice) })
+        }
+    }
+
+    /// Creates a new non-empty slice view.
+    ///
+    /// # Parameters
+    ///
+    /// - `slice`: The input slice.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self`: A new non-empty slice view.
+    ///
+    /// # Safety
+    ///
+    /// This function assumes that the slice is non-empty.
+    pub unsafe fn new_unchecked(slice: &[T]) -> &Self {
+        // SAFETY: `NonEmptySlice<T>` is a `#[repr(transparent)]` wrapper around `[T]`, so both
+        // references have the same layout and metadata.
+        unsafe { &*(slice as *const [T] as *const Self) }
+    }
+
+    /// Returns the underlying slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    /// Returns the number of elements in the slice.
+    pub fn len(&self) -> NonZeroUsize {
+        NonZeroUsize::new(self.0.len()).expect(NON_EMPTY_INVARIANT)
+    }
+
+    /// Returns the first element of the slice.
+    pub fn first(&self) -> &T {
+        self.0.first().expect(NON_EMPTY_INVARIANT)
+    }
+
+    /// Returns the last element of the slice.
+    pub fn last(&self) -> &T {
+        self.0.last().expect(NON_EMPTY_INVARIANT)
+    }
+
+    /// Returns the first element and the rest of the slice.
+    ///
+    /// # Returns
+    ///
+    /// - `(&T, &[T])`: The first element and the (possibly empty) slice of the remaining elements.
+    pub fn split_first(&self) -> (&T, &[T]) {
+        self.0.split_first().expect(NON_EMPTY_INVARIANT)
+    }
+
+    /// Returns the last element and the rest of the slice.
+    ///
+    /// # Returns
+    ///
+    /// - `(&T, &[T])`: The last element and the (possibly empty) slice of the preceding elements.
+    pub fn split_last(&self) -> (&T, &[T]) {
+        self.0.split_last().expect(NON_EMPTY_INVARIANT)
+    }
+
+    /// Returns the minimum element of the slice.
+    ///
+    /// If several elements are equally minimum, the first one is returned.
+    pub fn minimum(&self) -> &T
+    where
+        T: Ord,
+    {
+        self.0.iter().min().expect(NON_EMPTY_INVARIANT)
+    }
+
+    /// Returns the maximum element of the slice.
+    ///
+    /// If several elements are equally maximum, the last one is returned.
+    pub fn maximum(&self) -> &T
+    where
+        T: Ord,
+    {
+        self.0.iter().max().expect(NON_EMPTY_INVARIANT)
+    }
+
+    /// Returns a non-empty iterator over the elements of the slice.
+    pub fn iter(&self) -> NonEmptyIter<slice::Iter<'_, T>> {
+        self.into_non_empty_iter()
+    }
+
+    /// Copies the slice into a new non-empty vector.
+    pub fn to_vec(&self) -> NonEmptyVec<T>
+    where
+        T: Clone,
+    {
+        BoundedCollection(self.0.to_vec())
+    }
+
+    /// Returns a non-empty iterator over non-empty chunks of `size` elements, the last chunk
+    /// holding the remaining elements if `size` does not divide the length of the slice.
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: The number of elements of each chunk.
+    pub fn chunks_non_empty(&self, size: NonZeroUsize) -> NonEmptyIter<NonEmptyChunks<'_, T>> {
+        // SAFETY: the slice is non-empty, so it has at least one chunk.
+        unsafe { NonEmptyIter::new_unchecked(NonEmptyChunks::new(&self.0, size)) }
+    }
+
+    /// Returns an iterator over all the overlapping windows of `size` elements. The iterator is
+    /// empty if the slice is shorter than `size`.
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: The number of elements of each window.
+    pub fn windows_non_empty(&self, size: NonZeroUsize) -> NonEmptyWindows<'_, T> {
+        NonEmptyWindows::new(&self.0, size)
+    }
+}
+
+/// An iterator over non-empty chunks of a slice, created by `chunks_non_empty`.
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct NonEmptyChunks<'a, T>(slice::Chunks<'a, T>);
+
+impl<'a, T> NonEmptyChunks<'a, T> {
+    pub(crate) fn new(slice: &'a [T], size: NonZeroUsize) -> Self {
+        Self(slice.chunks(size.get()))
+    }
+}
+
+impl<'a, T> Iterator for NonEmptyChunks<'a, T> {
+    type Item = &'a NonEmptySlice<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: `slice::Chunks` never yields an empty chunk.
+        self.0
+            .next()
+            .map(|chunk| unsafe { NonEmptySlice::new_unchecked(chunk) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for NonEmptyChunks<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // SAFETY: `slice::Chunks` never yields an empty chunk.
+        self.0
+            .next_back()
+            .map(|chunk| unsafe { NonEmptySlice::new_unchecked(chunk) })
+    }
+}
+
+impl<T> ExactSizeIterator for NonEmptyChunks<'_, T> {}
+
+impl<T> FusedIterator for NonEmptyChunks<'_, T> {}
+
+/// An iterator over overlapping non-empty windows of a slice, created by `windows_non_empty`.
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct NonEmptyWindows<'a, T>(slice::Windows<'a, T>);
+
+impl<'a, T> NonEmptyWindows<'a, T> {
+    pub(crate) fn new(slice: &'a [T], size: NonZeroUsize) -> Self {
+        Self(slice.windows(size.get()))
+    }
+}
+
+impl<'a, T> Iterator for NonEmptyWindows<'a, T> {
+    type Item = &'a NonEmptySlice<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: `slice::Windows` never yields an empty window.
+        self.0
+            .next()
+            .map(|window| unsafe { NonEmptySlice::new_unchecked(window) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for NonEmptyWindows<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // SAFETY: `slice::Windows` never yields an empty window.
+        self.0
+            .next_back()
+            .map(|window| unsafe { NonEmptySlice::new_unchecked(window) })
+    }
+}
+
+impl<T> ExactSizeIterator for NonEmptyWindows<'_, T> {}
+
+impl<T> FusedIterator for NonEmptyWindows<'_, T> {}
+
+impl<T> Deref for NonEmptySlice<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> AsRef<[T]> for NonEmptySlice<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<'a, T> TryFrom<&'a [T]> for &'a NonEmptySlice<T> {
+    type Error = SequenceContentError;
+
+    fn try_from(slice: &'a [T]) -> Result<Self, Self::Error> {
+        NonEmptySlice::new(slice)
+    }
+}
+
+impl<T: Clone> ToOwned for NonEmptySlice<T> {
+    type Owned = NonEmptyVec<T>;
+
+    fn to_owned(&self) -> Self::Owned {
+        self.to_vec()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NonEmptySlice<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<T> IntoNonEmptyIterator for &NonEmptySlice<T> {
+    fn into_non_empty_iter(self) -> NonEmptyIter<Self::IntoIter> {
+        // SAFETY: the slice is non-empty, so is the iterator over its elements.
+        unsafe { NonEmptyIter::new_unchecked(self.0.iter()) }
+    }
+}
+
+impl<T> Borrow<NonEmptySlice<T>> for NonEmptyVec<T> {
+    fn borrow(&self) -> &NonEmptySlice<T> {
+        self.as_slice()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_non_empty_slice_new() {
+        let data = [1, 2, 3];
+
+        let non_empty_slice = NonEmptySlice::new(&data);
+        assert!(non_empty_slice.is_ok());
+        assert_eq!(non_empty_slice.unwrap().as_slice(), &data);
+
+        let empty_slice = NonEmptySlice::<i32>::new(&[]);
+        assert!(matches!(empty_slice, Err(SequenceContentError::Empty)));
+
+        let non_empty_slice: Result<&NonEmptySlice<i32>, _> = data.as_slice().try_into();
+        assert!(non_empty_slice.is_ok());
+        let empty_slice = <&NonEmptySlice<i32>>::try_from(&data[..0]);
+        assert!(empty_slice.is_err());
+    }
+
+    #[test]
+    fn test_non_empty_slice_accessors() {
+        let non_empty_slice = NonEmptySlice::new(&[2, 1, 3]).unwrap();
+
+        assert_eq!(non_empty_slice.len(), NonZeroUsize::new(3).unwrap());
+        assert_eq!(non_empty_slice.first(), &2);
+        assert_eq!(non_empty_slice.last(), &3);
+        assert_eq!(non_empty_slice.split_first(), (&2, &[1, 3][..]));
+        assert_eq!(non_empty_slice.split_last(), (&3, &[2, 1][..]));
+        assert_eq!(non_empty_slice.minimum(), &1);
+        assert_eq!(non_empty_slice.maximum(), &3);
+        assert!(non_empty_slice.contains(&1));
+
+        let doubled: NonEmptyVec<i32> = non_empty_slice.iter().map(|it| it * 2).collect_non_empty();
+        assert_eq!(doubled.into_inner(), vec![4, 2, 6]);
+        assert_eq!(non_empty_slice.to_vec().into_inner(), vec![2, 1, 3]);
+        assert_eq!(non_empty_slice.to_owned().into_inner(), vec![2, 1, 3]);
+    }
+
+    #[test]
+    fn test_non_empty_vec_as_slice() {
+        let non_empty_vec = NonEmptyVec::new(vec![1, 2, 3]).unwrap();
+
+        let non_empty_slice = non_empty_vec.as_slice();
+        assert_eq!(non_empty_slice.as_slice(), &[1, 2, 3]);
+        assert_eq!(
+            Borrow::<NonEmptySlice<i32>>::borrow(&non_empty_vec),
+            non_empty_slice
+        );
+    }
+
+    #[test]
+    fn test_non_empty_slice_chunks() {
+        let slice = NonEmptySlice::new(&[1, 2, 3, 4, 5]).unwrap();
+        let two = NonZeroUsize::new(2).unwrap();
+
+        let chunks: Vec<&[i32]> = slice
+            .chunks_non_empty(two)
+            .into_iter()
+            .map(NonEmptySlice::as_slice)
+            .collect();
+        assert_eq!(chunks, vec![&[1, 2][..], &[3, 4], &[5]]);
+        let (first, _) = slice
+            .chunks_non_empty(NonZeroUsize::new(8).unwrap())
+            .split_first();
+        assert_eq!(first.as_slice(), &[1, 2, 3, 4, 5]);
+        assert_eq!(
+            slice
+                .chunks_non_empty(two)
+                .into_iter()
+                .next_back()
+                .unwrap()
+                .first(),
+            &5
+        );
+
+        let windows: Vec<&[i32]> = slice
+            .windows_non_empty(NonZeroUsize::new(4).unwrap())
+            .map(NonEmptySlice::as_slice)
+            .collect();
+        assert_eq!(windows, vec![&[1, 2, 3, 4][..], &[2, 3, 4, 5]]);
+        assert_eq!(
+            slice.windows_non_empty(NonZeroUsize::new(6).unwrap()).len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_non_empty_vec_chunked() {
+        let non_empty_vec = NonEmptyVec::new(vec![1, 2, 3, 4, 5]).unwrap();
+        let two = NonZeroUsize::new(2).unwrap();
+
+        assert_eq!(non_empty_vec.chunks_non_empty(two).into_iter().count(), 3);
+        assert_eq!(non_empty_vec.windows_non_empty(two).count(), 4);
+
+        let chunked = non_empty_vec.chunked(two);
+        assert_eq!(chunked.len().get(), 3);
+        let chunked: Vec<Vec<i32>> = chunked.into_iter().map(NonEmptyVec::into_inner).collect();
+        assert_eq!(chunked, vec![vec![1, 2], vec![3, 4], vec![5]]);
+
+        let chunked = NonEmptyVec::singleton(1).chunked(NonZeroUsize::MIN);
+        assert_eq!(chunked.into_inner(), vec![NonEmptyVec::singleton(1)]);
+    }
+}
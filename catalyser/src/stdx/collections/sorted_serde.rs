@@ -0,0 +1,254 @@
+//! This module provides an opt-in deterministic serialization for hash-based collections, which
+//! otherwise serialize in the arbitrary order of their iteration.
+//!
+//! Collections implementing [SortedSerialize] are serialized with their elements (or their map
+//! entries) sorted by `Ord`, so that snapshots and content hashes are reproducible. Deserialization
+//! is unaffected.
+//!
+//! # Features
+//!
+//! - `Sorted` wraps a reference to a collection to serialize it in sorted order.
+//! - The module itself can be used with `#[serde(with = "...")]` on struct fields.
+//! - Supports `HashSet`, `HashMap`, `BinaryHeap`, `BTreeSet`, `BTreeMap` and any
+//!   `BoundedCollection` over them (e.g. `NonEmptyHashSet`), as well as the `hashbrown` and
+//!   `indexmap` collections when their features are enabled.
+//!
+//! # Usage Example
+//!
+//! ```rust
+//! use catalyser::stdx::collections::{sorted_serde::Sorted, NonEmptyHashSet};
+//! use std::collections::HashSet;
+//!
+//! let non_empty_hash_set = NonEmptyHashSet::new(HashSet::from([3, 1, 2])).unwrap();
+//! assert_eq!(serde_json::to_string(&Sorted(&non_empty_hash_set)).unwrap(), "[1,2,3]");
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Tags {
+//!     #[serde(with = "catalyser::stdx::collections::sorted_serde")]
+//!     tags: NonEmptyHashSet<String>,
+//! }
+//!
+//! let tags = Tags { tags: NonEmptyHashSet::from_head_tail("b".to_string(), ["a".to_string()]) };
+//! assert_eq!(serde_json::to_string(&tags).unwrap(), r#"{"tags":["a","b"]}"#);
+//! ```
+
+use crate::stdx::collections::BoundedCollection;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet},
+    hash::BuildHasher,
+};
+
+/// A trait for collections which can be serialized in a deterministic, sorted order.
+pub trait SortedSerialize {
+    /// Serializes the collection with its elements, or its map entries by key, sorted in
+    /// ascending order.
+    ///
+    /// # Parameters
+    /// - `serializer`: The serializer to write the collection to.
+    ///
+    /// # Returns
+    /// - The result of the serializer.
+    fn serialize_sorted<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+}
+
+/// A wrapper serializing the referenced collection in sorted order.
+#[derive(Debug, Clone, Copy)]
+pub struct Sorted<'a, T: ?Sized>(pub &'a T);
+
+impl<T> Serialize for Sorted<'_, T>
+where
+    T: SortedSerialize + ?Sized,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize_sorted(serializer)
+    }
+}
+
+/// Serializes the collection in sorted order, for use with `#[serde(with = "...")]` or
+/// `#[serde(serialize_with = "...")]`.
+///
+/// # Parameters
+/// - `value`: The collection to serialize.
+/// - `serializer`: The serializer to write the collection to.
+///
+/// # Returns
+/// - The result of the serializer.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: SortedSerialize + ?Sized,
+    S: Serializer,
+{
+    value.serialize_sorted(serializer)
+}
+
+/// Deserializes the collection as usual, for use with `#[serde(with = "...")]`.
+///
+/// # Parameters
+/// - `deserializer`: The deserializer to read the collection from.
+///
+/// # Returns
+/// - The deserialized collection, or the error of the deserializer.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    T::deserialize(deserializer)
+}
+
+/// Serializes the elements of a set-like collection as a sequence sorted by `Ord`.
+pub(crate) fn serialize_sorted_seq<'a, T, I, S>(elements: I, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Ord + Serialize + 'a,
+    I: IntoIterator<Item = &'a T>,
+    S: Serializer,
+{
+    let mut elements: Vec<&T> = elements.into_iter().collect();
+    elements.sort_unstable();
+    serializer.collect_seq(elements)
+}
+
+/// Serializes the entries of a map-like collection as a map sorted by key.
+pub(crate) fn serialize_sorted_map<'a, K, V, I, S>(entries: I, serializer: S) -> Result<S::Ok, S::Error>
+where
+    K: Ord + Serialize + 'a,
+    V: Serialize + 'a,
+    I: IntoIterator<Item = (&'a K, &'a V)>,
+    S: Serializer,
+{
+    let mut entries: Vec<(&K, &V)> = entries.into_iter().collect();
+    entries.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+    serializer.collect_map(entries)
+}
+
+impl<T, S> SortedSerialize for HashSet<T, S>
+where
+    T: Ord + Serialize,
+    S: BuildHasher,
+{
+    fn serialize_sorted<Z>(&self, serializer: Z) -> Result<Z::Ok, Z::Error>
+    where
+        Z: Serializer,
+    {
+        serialize_sorted_seq(self, serializer)
+    }
+}
+
+impl<K, V, S> SortedSerialize for HashMap<K, V, S>
+where
+    K: Ord + Serialize,
+    V: Serialize,
+    S: BuildHasher,
+{
+    fn serialize_sorted<Z>(&self, serializer: Z) -> Result<Z::Ok, Z::Error>
+    where
+        Z: Serializer,
+    {
+        serialize_sorted_map(self, serializer)
+    }
+}
+
+impl<T> SortedSerialize for BinaryHeap<T>
+where
+    T: Ord + Serialize,
+{
+    fn serialize_sorted<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_sorted_seq(self, serializer)
+    }
+}
+
+impl<T> SortedSerialize for BTreeSet<T>
+where
+    T: Ord + Serialize,
+{
+    fn serialize_sorted<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self)
+    }
+}
+
+impl<K, V> SortedSerialize for BTreeMap<K, V>
+where
+    K: Ord + Serialize,
+    V: Serialize,
+{
+    fn serialize_sorted<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_map(self)
+    }
+}
+
+impl<T, C, const MIN: usize, const MAX: usize> SortedSerialize for BoundedCollection<T, C, MIN, MAX>
+where
+    C: IntoIterator<Item = T> + Default + SortedSerialize,
+{
+    fn serialize_sorted<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize_sorted(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stdx::collections::{NonEmptyHashMap, NonEmptyHashSet};
+
+    #[test]
+    fn test_sorted_serialize() {
+        let hash_set: HashSet<i32> = (0..32).rev().collect();
+        let expected = serde_json::to_string(&(0..32).collect::<Vec<_>>()).unwrap();
+        assert_eq!(serde_json::to_string(&Sorted(&hash_set)).unwrap(), expected);
+
+        let non_empty_hash_map = NonEmptyHashMap::new(HashMap::from([("c", 3), ("a", 1), ("b", 2)])).unwrap();
+        assert_eq!(
+            serde_json::to_string(&Sorted(&non_empty_hash_map)).unwrap(),
+            r#"{"a":1,"b":2,"c":3}"#
+        );
+
+        let btree_set = BTreeSet::from([2, 1]);
+        assert_eq!(serde_json::to_string(&Sorted(&btree_set)).unwrap(), "[1,2]");
+    }
+
+    #[test]
+    fn test_sorted_serde_with() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Document {
+            #[serde(with = "crate::stdx::collections::sorted_serde")]
+            tags: NonEmptyHashSet<String>,
+            #[serde(with = "crate::stdx::collections::sorted_serde")]
+            scores: HashMap<String, u8>,
+        }
+
+        let document = Document {
+            tags: NonEmptyHashSet::from_head_tail("b".to_string(), ["c".to_string(), "a".to_string()]),
+            scores: HashMap::from([("y".to_string(), 2), ("x".to_string(), 1)]),
+        };
+
+        let serialized = serde_json::to_string(&document).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"tags":["a","b","c"],"scores":{"x":1,"y":2}}"#
+        );
+
+        let deserialized: Document = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, document);
+
+        let invalid: Result<Document, _> = serde_json::from_str(r#"{"tags":[],"scores":{}}"#);
+        assert!(invalid.is_err());
+    }
+}
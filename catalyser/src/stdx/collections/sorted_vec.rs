@@ -0,0 +1,681 @@
+//! This module defines `SortedVec`, a vector whose elements are kept sorted, and
+//! `NonEmptySortedVec`, its non-empty counterpart.
+//!
+//! The ordering is selected at the type level through the `SortOrder` trait: `Ascending`,
+//! `Descending` or any user-defined comparator, made strict (i.e. rejecting equal elements) by
+//! wrapping it in `Strict`. The behavior of serde deserialization on unsorted input is selected
+//! through the `SortPolicy` trait: `RejectUnsorted` (the default) or `SortUnsorted`.
+//!
+//! # Usage Example
+//!
+//! ```rust
+//! use catalyser::stdx::{
+//!     collections::sorted_vec::{Descending, SortedVec, Strict},
+//!     error::is_empty_sequence::SequenceContentError,
+//! };
+//!
+//! let mut sorted_vec = SortedVec::<_>::new(vec![1, 3, 3, 7]).unwrap();
+//! assert!(matches!(sorted_vec.insert(5), Ok(3)));
+//! assert_eq!(sorted_vec.binary_search(&7), Ok(4));
+//! assert_eq!(sorted_vec.range(3..6), &[3, 3, 5]);
+//!
+//! let unsorted = SortedVec::<_>::new(vec![1, 3, 2]);
+//! assert!(matches!(unsorted, Err(SequenceContentError::Unsorted { index: 2 })));
+//!
+//! let strictly_descending = SortedVec::<_, Strict<Descending>>::from_unsorted(vec![1, 3, 2, 3]);
+//! assert_eq!(strictly_descending.into_inner(), vec![3, 2, 1]);
+//! ```
+
+#[cfg(feature = "serde")]
+use crate::stdx::collections::bounded_deserialize::BoundedDeserialize;
+use crate::stdx::{
+    collections::{BoundedCollection, Length, NonEmptyCollection},
+    error::is_empty_sequence::SequenceContentError,
+};
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Serialize};
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::{Bound, Deref, RangeBounds},
+    slice, vec,
+};
+
+/// A trait defining the order of the elements of a `SortedVec`.
+///
+/// Implement it to use a custom comparator.
+pub trait SortOrder<T> {
+    /// Whether equal elements are rejected, i.e. whether the order is strict.
+    const STRICT: bool = false;
+
+    /// Compares two elements.
+    ///
+    /// # Returns
+    ///
+    /// - `Ordering::Less` if `a` must come before `b`.
+    /// - `Ordering::Equal` if `a` and `b` are equivalent.
+    /// - `Ordering::Greater` if `a` must come after `b`.
+    fn compare(a: &T, b: &T) -> Ordering;
+}
+
+/// Sorts the elements in ascending order, according to `Ord`.
+pub struct Ascending;
+
+impl<T: Ord> SortOrder<T> for Ascending {
+    fn compare(a: &T, b: &T) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+/// Sorts the elements in descending order, according to `Ord`.
+pub struct Descending;
+
+impl<T: Ord> SortOrder<T> for Descending {
+    fn compare(a: &T, b: &T) -> Ordering {
+        b.cmp(a)
+    }
+}
+
+/// Makes the order `O` strict, so that equivalent elements are rejected.
+pub struct Strict<O>(PhantomData<O>);
+
+impl<T, O: SortOrder<T>> SortOrder<T> for Strict<O> {
+    const STRICT: bool = true;
+
+    fn compare(a: &T, b: &T) -> Ordering {
+        O::compare(a, b)
+    }
+}
+
+/// A trait selecting how serde deserialization handles unsorted input.
+pub trait SortPolicy {
+    /// Whether unsorted input is sorted (`true`) or rejected (`false`).
+    const SORT: bool;
+}
+
+/// Rejects unsorted input on deserialization.
+pub struct RejectUnsorted;
+
+impl SortPolicy for RejectUnsorted {
+    const SORT: bool = false;
+}
+
+/// Sorts unsorted input on deserialization. With a strict order, equivalent elements are dropped.
+pub struct SortUnsorted;
+
+impl SortPolicy for SortUnsorted {
+    const SORT: bool = true;
+}
+
+/// A vector whose elements are sorted according to the order `O`.
+///
+/// `P` selects how serde deserialization handles unsorted input, and has no effect otherwise.
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct SortedVec<T, O = Ascending, P = RejectUnsorted>(Vec<T>, PhantomData<(O, P)>);
+
+impl<T, O: SortOrder<T>, P> SortedVec<T, O, P> {
+    /// Creates a new sorted vector.
+    ///
+    /// # Parameters
+    ///
+    /// - `vec`: The input vector.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Self)` if the vector is sorted according to `O`.
+    /// - `Err(SequenceContentError::Unsorted)` with the index of the first element out of order.
+    /// - `Err(SequenceContentError::Duplicate)` with the indices of the first two equivalent
+    ///   elements, if `O` is strict.
+    pub fn new(vec: Vec<T>) -> Result<Self, SequenceContentError> {
+        for (index, pair) in vec.windows(2).enumerate() {
+            match O::compare(&pair[0], &pair[1]) {
+                Ordering::Greater => return Err(SequenceContentError::Unsorted { index: index + 1 }),
+                Ordering::Equal if O::STRICT => {
+                    return Err(SequenceContentError::Duplicate {
+                        first: index,
+                        second: index + 1,
+                    });
+                },
+                _ => {},
+            }
+        }
+        Ok(Self(vec, PhantomData))
+    }
+
+    /// Creates a new sorted vector by sorting the input.
+    ///
+    /// The sort is stable. If `O` is strict, only the first of several equivalent elements is kept.
+    ///
+    /// # Parameters
+    ///
+    /// - `vec`: The input vector.
+    ///
+    /// # Returns
+    ///
+    /// - `Self`: A new sorted vector.
+    pub fn from_unsorted(mut vec: Vec<T>) -> Self {
+        vec.sort_by(O::compare);
+        if O::STRICT {
+            vec.dedup_by(|it, previous| O::compare(previous, it) == Ordering::Equal);
+        }
+        Self(vec, PhantomData)
+    }
+
+    /// Searches for an element equivalent to `value`, according to `O`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(index)` with the index of a matching element.
+    /// - `Err(index)` with the index where `value` could be inserted while keeping the order.
+    pub fn binary_search(&self, value: &T) -> Result<usize, usize> {
+        self.0.binary_search_by(|it| O::compare(it, value))
+    }
+
+    /// Returns `true` if the vector contains an element equivalent to `value`, according to `O`.
+    pub fn contains(&self, value: &T) -> bool {
+        self.binary_search(value).is_ok()
+    }
+
+    /// Returns the elements within `range`, whose bounds are expressed according to `O` (e.g.
+    /// `(Bound::Included(5), Bound::Included(2))` with `Descending`).
+    pub fn range<R>(&self, range: R) -> &[T]
+    where
+        R: RangeBounds<T>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(start) => self
+                .0
+                .partition_point(|it| O::compare(it, start) == Ordering::Less),
+            Bound::Excluded(start) => self
+                .0
+                .partition_point(|it| O::compare(it, start) != Ordering::Greater),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => self
+                .0
+                .partition_point(|it| O::compare(it, end) != Ordering::Greater),
+            Bound::Excluded(end) => self
+                .0
+                .partition_point(|it| O::compare(it, end) == Ordering::Less),
+            Bound::Unbounded => self.0.len(),
+        };
+        &self.0[start..end.max(start)]
+    }
+
+    /// Inserts an element at the position keeping the order, after any equivalent element.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The element to insert.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(index)` with the index of the inserted element.
+    /// - `Err(SequenceContentError::Duplicate)` if `O` is strict and an equivalent element is
+    ///   already present, with its index and the index the new one would have had. The vector is
+    ///   left unchanged.
+    pub fn insert(&mut self, value: T) -> Result<usize, SequenceContentError> {
+        let index = self
+            .0
+            .partition_point(|it| O::compare(it, &value) != Ordering::Greater);
+        if O::STRICT && index > 0 && O::compare(&self.0[index - 1], &value) == Ordering::Equal {
+            return Err(SequenceContentError::Duplicate {
+                first: index - 1,
+                second: index,
+            });
+        }
+        self.0.insert(index, value);
+        Ok(index)
+    }
+
+    /// Merges two sorted vectors in linear time.
+    ///
+    /// Equivalent elements of `self` come before those of `other`. If `O` is strict, the elements
+    /// of `other` equivalent to an element of `self` are dropped.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The sorted vector to merge with.
+    ///
+    /// # Returns
+    ///
+    /// - `Self`: The merged sorted vector.
+    pub fn merge(self, other: Self) -> Self {
+        let mut merged = Vec::with_capacity(self.0.len() + other.0.len());
+        let mut left = self.0.into_iter().peekable();
+        let mut right = other.0.into_iter().peekable();
+
+        while let (Some(left_value), Some(right_value)) = (left.peek(), right.peek()) {
+            match O::compare(left_value, right_value) {
+                Ordering::Less => merged.extend(left.next()),
+                Ordering::Greater => merged.extend(right.next()),
+                Ordering::Equal => {
+                    merged.extend(left.next());
+                    if O::STRICT {
+                        right.next();
+                    }
+                },
+            }
+        }
+        merged.extend(left);
+        merged.extend(right);
+
+        Self(merged, PhantomData)
+    }
+}
+
+impl<T, O, P> SortedVec<T, O, P> {
+    /// Creates a new sorted vector.
+    ///
+    /// # Parameters
+    ///
+    /// - `vec`: The input vector.
+    ///
+    /// # Returns
+    ///
+    /// - `Self`: A new sorted vector.
+    ///
+    /// # Safety
+    ///
+    /// This function assumes that the vector is sorted according to `O`.
+    pub unsafe fn new_unchecked(vec: Vec<T>) -> Self {
+        Self(vec, PhantomData)
+    }
+
+    /// Returns the inner vector.
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+
+    /// Returns a reference to the inner vector.
+    pub fn as_inner(&self) -> &Vec<T> {
+        &self.0
+    }
+
+    /// Removes the last element and returns it, or `None` if the vector is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.0.pop()
+    }
+
+    /// Removes and returns the element at position `index`, shifting all elements after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        self.0.remove(index)
+    }
+
+    /// Retains only the elements for which `f` returns `true`, preserving their order.
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.0.retain(f)
+    }
+}
+
+impl<T, O, P> Default for SortedVec<T, O, P> {
+    fn default() -> Self {
+        Self(Vec::new(), PhantomData)
+    }
+}
+
+impl<T, O, P> Deref for SortedVec<T, O, P> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, O, P> AsRef<Vec<T>> for SortedVec<T, O, P> {
+    fn as_ref(&self) -> &Vec<T> {
+        &self.0
+    }
+}
+
+impl<T, O, P> AsRef<[T]> for SortedVec<T, O, P> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, O, P> Borrow<Vec<T>> for SortedVec<T, O, P> {
+    fn borrow(&self) -> &Vec<T> {
+        &self.0
+    }
+}
+
+impl<T, O, P> IntoIterator for SortedVec<T, O, P> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T, O, P> IntoIterator for &'a SortedVec<T, O, P> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<T, O, P> Length for SortedVec<T, O, P> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<T: Debug, O, P> Debug for SortedVec<T, O, P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("SortedVec").field(&self.0).finish()
+    }
+}
+
+impl<T: Clone, O, P> Clone for SortedVec<T, O, P> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<T: PartialEq, O, P> PartialEq for SortedVec<T, O, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Eq, O, P> Eq for SortedVec<T, O, P> {}
+
+impl<T: PartialOrd, O, P> PartialOrd for SortedVec<T, O, P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<T: Ord, O, P> Ord for SortedVec<T, O, P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T: Hash, O, P> Hash for SortedVec<T, O, P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<T, O: SortOrder<T>, P> TryFrom<Vec<T>> for SortedVec<T, O, P> {
+    type Error = SequenceContentError;
+
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        Self::new(vec)
+    }
+}
+
+impl<T, O, P> From<SortedVec<T, O, P>> for Vec<T> {
+    fn from(sorted_vec: SortedVec<T, O, P>) -> Self {
+        sorted_vec.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, O, P> Deserialize<'de> for SortedVec<T, O, P>
+where
+    T: Deserialize<'de>,
+    O: SortOrder<T>,
+    P: SortPolicy,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Self::deserialize_bounded(deserializer, 0, usize::MAX)
+    }
+}
+
+/// The length is checked on the elements as they are read, before the duplicates of a strict
+/// order are removed by the `SortUnsorted` policy.
+#[cfg(feature = "serde")]
+impl<'de, T, O, P> BoundedDeserialize<'de> for SortedVec<T, O, P>
+where
+    T: Deserialize<'de>,
+    O: SortOrder<T>,
+    P: SortPolicy,
+{
+    fn deserialize_bounded<D>(deserializer: D, min: usize, max: usize) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let vec = Vec::deserialize_bounded(deserializer, min, max)?;
+        if P::SORT {
+            Ok(Self::from_unsorted(vec))
+        } else {
+            Self::new(vec).map_err(D::Error::custom)
+        }
+    }
+}
+
+/// Specialized type for non-empty sorted vectors.
+pub type NonEmptySortedVec<T, O = Ascending, P = RejectUnsorted> = NonEmptyCollection<T, SortedVec<T, O, P>>;
+
+impl<T, O: SortOrder<T>, P, const MIN: usize, const MAX: usize> TryFrom<Vec<T>> for BoundedCollection<T, SortedVec<T, O, P>, MIN, MAX> {
+    type Error = SequenceContentError;
+
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        Self::new(SortedVec::new(vec)?)
+    }
+}
+
+impl<T, O: SortOrder<T>, P> NonEmptySortedVec<T, O, P> {
+    /// Inserts an element at the position keeping the order, after any equivalent element.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(index)` with the index of the inserted element.
+    /// - `Err(SequenceContentError::Duplicate)` if `O` is strict and an equivalent element is
+    ///   already present.
+    pub fn insert(&mut self, value: T) -> Result<usize, SequenceContentError> {
+        self.0.insert(value)
+    }
+
+    /// Merges two non-empty sorted vectors in linear time, keeping the non-empty guarantee.
+    pub fn merge(self, other: Self) -> Self {
+        BoundedCollection(self.0.merge(other.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stdx::collections::{NonEmptySequence, NonEmptyVec};
+
+    struct ByLength;
+
+    impl SortOrder<&str> for ByLength {
+        fn compare(a: &&str, b: &&str) -> Ordering {
+            a.len().cmp(&b.len())
+        }
+    }
+
+    #[test]
+    fn test_sorted_vec_new() {
+        let sorted_vec = SortedVec::<_>::new(vec![1, 2, 2, 3]);
+        assert_eq!(sorted_vec.unwrap().into_inner(), vec![1, 2, 2, 3]);
+
+        let sorted_vec = SortedVec::<_>::new(vec![1, 3, 2]);
+        assert!(matches!(
+            sorted_vec,
+            Err(SequenceContentError::Unsorted { index: 2 })
+        ));
+
+        let sorted_vec = SortedVec::<_, Strict<Ascending>>::new(vec![1, 2, 2, 3]);
+        assert!(matches!(
+            sorted_vec,
+            Err(SequenceContentError::Duplicate {
+                first: 1,
+                second: 2
+            })
+        ));
+
+        let sorted_vec = SortedVec::<_, Descending>::new(vec![3, 2, 2, 1]);
+        assert!(sorted_vec.is_ok());
+
+        let sorted_vec = SortedVec::<_, ByLength>::new(vec!["c", "ab", "b", "abc"]);
+        assert!(matches!(
+            sorted_vec,
+            Err(SequenceContentError::Unsorted { index: 2 })
+        ));
+
+        let sorted_vec = SortedVec::<i32>::new(Vec::new());
+        assert!(sorted_vec.is_ok());
+
+        let sorted_vec: Result<SortedVec<i32>, _> = vec![2, 1].try_into();
+        assert!(sorted_vec.is_err());
+    }
+
+    #[test]
+    fn test_sorted_vec_from_unsorted() {
+        let sorted_vec = SortedVec::<_>::from_unsorted(vec![3, 1, 2, 1]);
+        assert_eq!(sorted_vec.into_inner(), vec![1, 1, 2, 3]);
+
+        let sorted_vec = SortedVec::<_, Strict<Ascending>>::from_unsorted(vec![3, 1, 2, 1]);
+        assert_eq!(sorted_vec.into_inner(), vec![1, 2, 3]);
+
+        let sorted_vec = SortedVec::<_, ByLength>::from_unsorted(vec!["abc", "b", "ab", "a"]);
+        assert_eq!(sorted_vec.into_inner(), vec!["b", "a", "ab", "abc"]);
+
+        let sorted_vec = SortedVec::<_, Strict<ByLength>>::from_unsorted(vec!["abc", "b", "ab", "a"]);
+        assert_eq!(sorted_vec.into_inner(), vec!["b", "ab", "abc"]);
+    }
+
+    #[test]
+    fn test_sorted_vec_queries() {
+        let sorted_vec = SortedVec::<_>::new(vec![1, 3, 3, 5, 7]).unwrap();
+
+        assert!(matches!(sorted_vec.binary_search(&3), Ok(1 | 2)));
+        assert_eq!(sorted_vec.binary_search(&4), Err(3));
+        assert!(sorted_vec.contains(&5));
+        assert!(!sorted_vec.contains(&6));
+
+        assert_eq!(sorted_vec.range(3..7), &[3, 3, 5]);
+        assert_eq!(sorted_vec.range(3..=7), &[3, 3, 5, 7]);
+        assert_eq!(
+            sorted_vec.range((Bound::Excluded(3), Bound::Unbounded)),
+            &[5, 7]
+        );
+        assert_eq!(sorted_vec.range(..3), &[1]);
+        assert_eq!(
+            sorted_vec.range((Bound::Included(6), Bound::Excluded(2))),
+            &[] as &[i32]
+        );
+
+        let sorted_vec = SortedVec::<_, Descending>::new(vec![7, 5, 3, 1]).unwrap();
+        assert_eq!(sorted_vec.binary_search(&5), Ok(1));
+        assert_eq!(
+            sorted_vec.range((Bound::Included(5), Bound::Included(1))),
+            &[5, 3, 1]
+        );
+    }
+
+    #[test]
+    fn test_sorted_vec_mutation() {
+        let mut sorted_vec = SortedVec::<_>::new(vec![1, 3, 5]).unwrap();
+        assert!(matches!(sorted_vec.insert(4), Ok(2)));
+        assert!(matches!(sorted_vec.insert(3), Ok(2)));
+        assert!(matches!(sorted_vec.insert(0), Ok(0)));
+        assert_eq!(sorted_vec.as_inner(), &vec![0, 1, 3, 3, 4, 5]);
+
+        assert_eq!(sorted_vec.remove(0), 0);
+        assert_eq!(sorted_vec.pop(), Some(5));
+        sorted_vec.retain(|it| *it != 3);
+        assert_eq!(sorted_vec.as_inner(), &vec![1, 4]);
+
+        let mut strict_vec = SortedVec::<_, Strict<Ascending>>::new(vec![1, 3]).unwrap();
+        assert!(matches!(
+            strict_vec.insert(3),
+            Err(SequenceContentError::Duplicate {
+                first: 1,
+                second: 2
+            })
+        ));
+        assert!(matches!(strict_vec.insert(2), Ok(1)));
+    }
+
+    #[test]
+    fn test_sorted_vec_merge() {
+        let left = SortedVec::<_>::new(vec![1, 3, 5]).unwrap();
+        let right = SortedVec::<_>::new(vec![2, 3, 6, 7]).unwrap();
+        assert_eq!(left.merge(right).into_inner(), vec![1, 2, 3, 3, 5, 6, 7]);
+
+        let left = SortedVec::<_, Strict<Ascending>>::new(vec![1, 3, 5]).unwrap();
+        let right = SortedVec::<_, Strict<Ascending>>::new(vec![2, 3, 6]).unwrap();
+        assert_eq!(left.merge(right).into_inner(), vec![1, 2, 3, 5, 6]);
+
+        let left = SortedVec::<_, ByLength>::new(vec!["a", "abc"]).unwrap();
+        let right = SortedVec::<_, ByLength>::new(vec!["b", "cd"]).unwrap();
+        assert_eq!(left.merge(right).into_inner(), vec!["a", "b", "cd", "abc"]);
+    }
+
+    #[test]
+    fn test_non_empty_sorted_vec() {
+        let mut non_empty_sorted_vec = NonEmptySortedVec::<_>::try_from(vec![2, 4]).unwrap();
+        assert_eq!(non_empty_sorted_vec.first(), &2);
+        assert!(matches!(non_empty_sorted_vec.insert(3), Ok(1)));
+        assert_eq!(non_empty_sorted_vec.range(3..), &[3, 4]);
+
+        let other = NonEmptySortedVec::<_>::try_from(vec![1]).unwrap();
+        let merged = non_empty_sorted_vec.merge(other);
+        assert_eq!(merged.len().get(), 4);
+        assert_eq!(NonEmptyVec::from(merged).into_inner(), vec![1, 2, 3, 4]);
+
+        let sorted_vec = SortedVec::from(NonEmptySortedVec::<_>::try_from(vec![1]).unwrap());
+        assert_eq!(sorted_vec.into_inner(), vec![1]);
+
+        let empty = NonEmptySortedVec::<i32>::try_from(Vec::new());
+        assert!(matches!(empty, Err(SequenceContentError::Empty)));
+        let unsorted = NonEmptySortedVec::<_>::try_from(vec![2, 1]);
+        assert!(matches!(
+            unsorted,
+            Err(SequenceContentError::Unsorted { index: 1 })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_sorted_vec_serde() {
+        let sorted_vec = SortedVec::<_>::new(vec![1, 2, 3]).unwrap();
+
+        let serialized = serde_json::to_string(&sorted_vec).unwrap();
+        assert_eq!(serialized, "[1,2,3]");
+
+        let deserialized: SortedVec<i32> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, sorted_vec);
+
+        let deserialized: Result<SortedVec<i32>, _> = serde_json::from_str("[3,1,2]");
+        assert!(deserialized.unwrap_err().to_string().contains("index 1"));
+
+        let deserialized: SortedVec<i32, Ascending, SortUnsorted> = serde_json::from_str("[3,1,2]").unwrap();
+        assert_eq!(deserialized.into_inner(), vec![1, 2, 3]);
+
+        let deserialized: SortedVec<i32, Strict<Descending>, SortUnsorted> = serde_json::from_str("[1,3,1,2]").unwrap();
+        assert_eq!(deserialized.into_inner(), vec![3, 2, 1]);
+
+        let deserialized: Result<NonEmptySortedVec<i32, Ascending, SortUnsorted>, _> = serde_json::from_str("[]");
+        assert!(deserialized.is_err());
+    }
+}
@@ -0,0 +1,231 @@
+//! This module provides non-empty collections over the `arrayvec` library, which store up to a
+//! fixed number of elements inline.
+//!
+//! Combined with a [BoundedCollection], the maximum can be checked on construction rather than
+//! when pushing into a full `ArrayVec`. For the same reason, `NonEmptyArrayVec` is not a
+//! `GrowableCollection`: it provides its own `singleton` and a fallible `try_from_head_tail`
+//! instead of the infallible constructors of the growable collections.
+//!
+//! # Usage Example
+//!
+//! ```rust
+//! use arrayvec::ArrayVec;
+//! use catalyser::stdx::collections::{third_party::arrayvec::NonEmptyArrayVec, NonEmptySequence};
+//!
+//! let non_empty_array_vec = NonEmptyArrayVec::<i32, 4>::new(ArrayVec::from_iter([1, 2, 3])).unwrap();
+//! assert_eq!(non_empty_array_vec.first(), &1);
+//! assert_eq!(non_empty_array_vec.remaining_capacity(), 1);
+//!
+//! assert!(NonEmptyArrayVec::<i32, 4>::new(ArrayVec::new()).is_err());
+//! ```
+
+#[cfg(feature = "serde")]
+use crate::stdx::collections::bounded_deserialize::{check_max, check_min, expecting_length, BoundedDeserialize};
+use crate::stdx::{
+    collections::{BoundedCollection, Length, NonEmptyCollection},
+    error::is_empty_sequence::SequenceContentError,
+};
+use arrayvec::ArrayVec;
+#[cfg(feature = "serde")]
+use serde::{
+    de::{SeqAccess, Visitor},
+    Deserialize, Deserializer,
+};
+use std::iter;
+#[cfg(feature = "serde")]
+use std::{
+    fmt::{self, Formatter},
+    marker::PhantomData,
+};
+
+impl<T, const CAP: usize> Length for ArrayVec<T, CAP> {
+    fn len(&self) -> usize {
+        ArrayVec::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        ArrayVec::is_empty(self)
+    }
+}
+
+/// Specialized type for non-empty array-backed vectors with a capacity of `CAP` elements.
+pub type NonEmptyArrayVec<T, const CAP: usize> = NonEmptyCollection<T, ArrayVec<T, CAP>>;
+
+impl<T, const CAP: usize> NonEmptyArrayVec<T, CAP> {
+    /// Creates a new non-empty array vector containing a single element.
+    ///
+    /// A capacity of `0` is rejected at compile time.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The only element of the array vector.
+    ///
+    /// # Returns
+    ///
+    /// - `Self`: A new non-empty array vector.
+    ///
+    /// # Examples
+    ///
+    /// ```compile_fail
+    /// use catalyser::stdx::collections::third_party::arrayvec::NonEmptyArrayVec;
+    ///
+    /// let non_empty_array_vec = NonEmptyArrayVec::<i32, 0>::singleton(1);
+    /// ```
+    pub fn singleton(value: T) -> Self {
+        const { assert!(CAP > 0, "`CAP` must be greater than 0") };
+
+        let mut array_vec = ArrayVec::new();
+        array_vec.push(value);
+        Self(array_vec)
+    }
+
+    /// Creates a new non-empty array vector from a required first element and the remaining ones,
+    /// failing rather than overflowing its capacity.
+    ///
+    /// A capacity of `0` is rejected at compile time.
+    ///
+    /// # Parameters
+    ///
+    /// - `head`: The first element of the array vector.
+    /// - `tail`: The remaining (possibly zero) elements of the array vector.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Self)` if `head` and `tail` hold at most `CAP` elements.
+    /// - `Err(SequenceContentError::TooMany)` as soon as the element exceeding the capacity is
+    ///   reached, the rest of `tail` being left unread.
+    pub fn try_from_head_tail<I>(head: T, tail: I) -> Result<Self, SequenceContentError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        const { assert!(CAP > 0, "`CAP` must be greater than 0") };
+
+        let mut array_vec = ArrayVec::new();
+        for element in iter::once(head).chain(tail) {
+            if array_vec.try_push(element).is_err() {
+                return Err(SequenceContentError::TooMany {
+                    max: CAP,
+                    actual: CAP + 1,
+                });
+            }
+        }
+        Ok(Self(array_vec))
+    }
+}
+
+impl<T, const CAP: usize, const MIN: usize, const MAX: usize> TryFrom<ArrayVec<T, CAP>> for BoundedCollection<T, ArrayVec<T, CAP>, MIN, MAX> {
+    type Error = SequenceContentError;
+
+    fn try_from(collection: ArrayVec<T, CAP>) -> Result<Self, Self::Error> {
+        Self::new(collection)
+    }
+}
+
+impl<T, const CAP: usize, const MIN: usize, const MAX: usize> From<BoundedCollection<T, ArrayVec<T, CAP>, MIN, MAX>> for ArrayVec<T, CAP> {
+    fn from(collection: BoundedCollection<T, ArrayVec<T, CAP>, MIN, MAX>) -> Self {
+        collection.0
+    }
+}
+
+/// A visitor pushing the elements of a sequence into an `ArrayVec`, failing rather than
+/// overflowing its capacity.
+#[cfg(feature = "serde")]
+struct ArrayVecVisitor<T, const CAP: usize> {
+    min: usize,
+    max: usize,
+    marker: PhantomData<fn() -> T>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, const CAP: usize> Visitor<'de> for ArrayVecVisitor<T, CAP>
+where
+    T: Deserialize<'de>,
+{
+    type Value = ArrayVec<T, CAP>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        expecting_length(formatter, "sequence", self.min, self.max)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut array_vec = ArrayVec::new();
+        while let Some(element) = seq.next_element()? {
+            check_max(array_vec.len() + 1, self.max)?;
+            array_vec.push(element);
+        }
+        check_min(array_vec.len(), self.min)?;
+        Ok(array_vec)
+    }
+}
+
+/// The maximum is capped to the capacity of the `ArrayVec`, so that an oversized payload is
+/// rejected rather than overflowing it.
+#[cfg(feature = "serde")]
+impl<'de, T, const CAP: usize> BoundedDeserialize<'de> for ArrayVec<T, CAP>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize_bounded<D>(deserializer: D, min: usize, max: usize) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(ArrayVecVisitor::<T, CAP> {
+            min,
+            max: max.min(CAP),
+            marker: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stdx::collections::NonEmptySequence;
+
+    #[test]
+    fn test_non_empty_array_vec() {
+        let array_vec = ArrayVec::from([3, 1, 2]);
+
+        let non_empty_array_vec = NonEmptyArrayVec::new(array_vec.clone()).unwrap();
+        assert_eq!(non_empty_array_vec.last(), &2);
+        assert_eq!(non_empty_array_vec.maximum(), &3);
+        assert_eq!(non_empty_array_vec.len().get(), 3);
+        assert!(non_empty_array_vec.is_full());
+        assert_eq!(ArrayVec::from(non_empty_array_vec), array_vec);
+
+        let non_empty_array_vec = NonEmptyArrayVec::<i32, 2>::singleton(1);
+        assert_eq!(non_empty_array_vec.as_slice(), &[1]);
+
+        let non_empty_array_vec = NonEmptyArrayVec::<i32, 3>::try_from_head_tail(1, [2, 3]).unwrap();
+        assert_eq!(non_empty_array_vec.as_slice(), &[1, 2, 3]);
+
+        let overflow = NonEmptyArrayVec::<i32, 2>::try_from_head_tail(1, 2..);
+        assert!(matches!(
+            overflow,
+            Err(SequenceContentError::TooMany { max: 2, actual: 3 })
+        ));
+
+        let empty = NonEmptyArrayVec::<i32, 2>::try_from(ArrayVec::new());
+        assert!(matches!(empty, Err(SequenceContentError::Empty)));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_non_empty_array_vec_serde() {
+        let non_empty_array_vec = NonEmptyArrayVec::new(ArrayVec::from([1, 2, 3])).unwrap();
+
+        let serialized = serde_json::to_string(&non_empty_array_vec).unwrap();
+        assert_eq!(serialized, "[1,2,3]");
+        let deserialized: NonEmptyArrayVec<i32, 3> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, non_empty_array_vec);
+        assert!(serde_json::from_str::<NonEmptyArrayVec<i32, 3>>("[]").is_err());
+        let overflow = serde_json::from_str::<NonEmptyArrayVec<i32, 2>>("[1,2,3]");
+        assert!(overflow
+            .unwrap_err()
+            .to_string()
+            .contains("too many elements (maximum: 2, actual: 3)"));
+    }
+}
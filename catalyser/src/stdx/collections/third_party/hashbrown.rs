@@ -0,0 +1,148 @@
+//! This module provides non-empty collections over the `hashbrown` library.
+//!
+//! # Usage Example
+//!
+//! ```rust
+//! use catalyser::stdx::collections::third_party::hashbrown::NonEmptyHashSet;
+//! use hashbrown::HashSet;
+//!
+//! let non_empty_hash_set = NonEmptyHashSet::new(HashSet::from([1, 2, 3])).unwrap();
+//! assert_eq!(non_empty_hash_set.len().get(), 3);
+//!
+//! assert!(NonEmptyHashSet::<i32>::new(HashSet::new()).is_err());
+//! ```
+
+#[cfg(feature = "serde")]
+use crate::stdx::collections::bounded_deserialize::impl_bounded_deserialize;
+#[cfg(feature = "serde")]
+use crate::stdx::collections::sorted_serde::{serialize_sorted_map, serialize_sorted_seq, SortedSerialize};
+use crate::stdx::{
+    collections::{impl_collection_conversion, impl_length, BoundedCollection, GrowableCollection, Length, NonEmptyCollection},
+    error::is_empty_sequence::SequenceContentError,
+};
+use hashbrown::{HashMap, HashSet};
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer};
+#[cfg(feature = "serde")]
+use std::hash::{BuildHasher, Hash};
+
+impl_length!(HashMap<K, V, S>);
+impl_length!(HashSet<T, S>);
+
+// SAFETY: the `hashbrown` collections have an unbounded capacity and keep every element they are
+// extended with, up to duplicates.
+unsafe impl<K, V, S> GrowableCollection for HashMap<K, V, S> {}
+unsafe impl<T, S> GrowableCollection for HashSet<T, S> {}
+
+/// Specialized type for non-empty `hashbrown` hash maps.
+pub type NonEmptyHashMap<K, V> = NonEmptyCollection<(K, V), HashMap<K, V>>;
+
+/// Specialized type for non-empty `hashbrown` hash sets.
+pub type NonEmptyHashSet<T> = NonEmptyCollection<T, HashSet<T>>;
+
+impl_collection_conversion!(impl<K, V> HashMap<K, V> => (K, V));
+impl_collection_conversion!(impl<T> HashSet<T> => T);
+
+#[cfg(feature = "serde")]
+impl<T, S> SortedSerialize for HashSet<T, S>
+where
+    T: Ord + Serialize,
+    S: BuildHasher,
+{
+    fn serialize_sorted<Z>(&self, serializer: Z) -> Result<Z::Ok, Z::Error>
+    where
+        Z: Serializer,
+    {
+        serialize_sorted_seq(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<K, V, S> SortedSerialize for HashMap<K, V, S>
+where
+    K: Ord + Serialize,
+    V: Serialize,
+    S: BuildHasher,
+{
+    fn serialize_sorted<Z>(&self, serializer: Z) -> Result<Z::Ok, Z::Error>
+    where
+        Z: Serializer,
+    {
+        serialize_sorted_map(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl_bounded_deserialize!(seq impl<T, S> HashSet<T, S> => T where T: Eq + Hash, S: BuildHasher + Default);
+#[cfg(feature = "serde")]
+impl_bounded_deserialize!(map impl<K, V, S> HashMap<K, V, S> => (K, V) where K: Eq + Hash, S: BuildHasher + Default);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stdx::collections::NonEmptySequence;
+
+    #[test]
+    fn test_non_empty_hashbrown_map() {
+        let hash_map = HashMap::from([(1, "a"), (2, "b")]);
+
+        let non_empty_hash_map = NonEmptyHashMap::new(hash_map.clone()).unwrap();
+        assert_eq!(non_empty_hash_map.len().get(), 2);
+        assert_eq!(non_empty_hash_map.get(&2), Some(&"b"));
+        assert_eq!(HashMap::from(non_empty_hash_map), hash_map);
+
+        let non_empty_hash_map = NonEmptyHashMap::singleton((1, "a"));
+        assert_eq!(non_empty_hash_map.first(), (&1, &"a"));
+
+        let empty = NonEmptyHashMap::<i32, &str>::try_from(HashMap::new());
+        assert!(matches!(empty, Err(SequenceContentError::Empty)));
+    }
+
+    #[test]
+    fn test_non_empty_hashbrown_set() {
+        let hash_set = HashSet::from([1, 2, 3]);
+
+        let non_empty_hash_set = NonEmptyHashSet::new(hash_set.clone()).unwrap();
+        assert_eq!(non_empty_hash_set.maximum(), &3);
+        assert_eq!(HashSet::from(non_empty_hash_set), hash_set);
+
+        let non_empty_hash_set = NonEmptyHashSet::from_head_tail(1, [1, 2]);
+        assert_eq!(non_empty_hash_set.len().get(), 2);
+
+        let empty = NonEmptyHashSet::<i32>::try_from(HashSet::new());
+        assert!(matches!(empty, Err(SequenceContentError::Empty)));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_non_empty_hashbrown_collections_serde() {
+        use crate::stdx::collections::sorted_serde::Sorted;
+
+        let non_empty_hash_map = NonEmptyHashMap::new(HashMap::from([("a".to_string(), 1)])).unwrap();
+
+        let serialized = serde_json::to_string(&non_empty_hash_map).unwrap();
+        assert_eq!(serialized, r#"{"a":1}"#);
+        let deserialized: NonEmptyHashMap<String, i32> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, non_empty_hash_map);
+        assert!(serde_json::from_str::<NonEmptyHashMap<String, i32>>("{}").is_err());
+
+        let non_empty_hash_set = NonEmptyHashSet::new(HashSet::from([1])).unwrap();
+
+        let serialized = serde_json::to_string(&non_empty_hash_set).unwrap();
+        assert_eq!(serialized, "[1]");
+        let deserialized: NonEmptyHashSet<i32> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, non_empty_hash_set);
+        assert!(serde_json::from_str::<NonEmptyHashSet<i32>>("[]").is_err());
+
+        let non_empty_hash_set = NonEmptyHashSet::new(HashSet::from([3, 1, 2])).unwrap();
+        assert_eq!(
+            serde_json::to_string(&Sorted(&non_empty_hash_set)).unwrap(),
+            "[1,2,3]"
+        );
+        let non_empty_hash_map = NonEmptyHashMap::new(HashMap::from([("b", 2), ("a", 1)])).unwrap();
+        assert_eq!(
+            serde_json::to_string(&Sorted(&non_empty_hash_map)).unwrap(),
+            r#"{"a":1,"b":2}"#
+        );
+    }
+}
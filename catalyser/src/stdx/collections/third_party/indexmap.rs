@@ -0,0 +1,154 @@
+//! This module provides non-empty collections over the `indexmap` library, which preserve the
+//! insertion order of their entries.
+//!
+//! # Usage Example
+//!
+//! ```rust
+//! use catalyser::stdx::collections::{third_party::indexmap::NonEmptyIndexMap, NonEmptySequence};
+//! use indexmap::IndexMap;
+//!
+//! let non_empty_index_map = NonEmptyIndexMap::new(IndexMap::from([("b", 2), ("a", 1)])).unwrap();
+//! assert_eq!(non_empty_index_map.first(), (&"b", &2));
+//! assert_eq!(non_empty_index_map.len().get(), 2);
+//!
+//! assert!(NonEmptyIndexMap::<&str, i32>::new(IndexMap::new()).is_err());
+//! ```
+
+#[cfg(feature = "serde")]
+use crate::stdx::collections::bounded_deserialize::impl_bounded_deserialize;
+#[cfg(feature = "serde")]
+use crate::stdx::collections::sorted_serde::{serialize_sorted_map, serialize_sorted_seq, SortedSerialize};
+use crate::stdx::{
+    collections::{impl_collection_conversion, impl_length, BoundedCollection, GrowableCollection, Length, NonEmptyCollection},
+    error::is_empty_sequence::SequenceContentError,
+};
+use indexmap::{IndexMap, IndexSet};
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer};
+#[cfg(feature = "serde")]
+use std::hash::{BuildHasher, Hash};
+
+impl_length!(IndexMap<K, V, S>);
+impl_length!(IndexSet<T, S>);
+
+// SAFETY: the `indexmap` collections have an unbounded capacity and keep every element they are
+// extended with, up to duplicates.
+unsafe impl<K, V, S> GrowableCollection for IndexMap<K, V, S> {}
+unsafe impl<T, S> GrowableCollection for IndexSet<T, S> {}
+
+/// Specialized type for non-empty insertion-ordered maps.
+pub type NonEmptyIndexMap<K, V> = NonEmptyCollection<(K, V), IndexMap<K, V>>;
+
+/// Specialized type for non-empty insertion-ordered sets.
+pub type NonEmptyIndexSet<T> = NonEmptyCollection<T, IndexSet<T>>;
+
+impl_collection_conversion!(impl<K, V> IndexMap<K, V> => (K, V));
+impl_collection_conversion!(impl<T> IndexSet<T> => T);
+
+#[cfg(feature = "serde")]
+impl<T, S> SortedSerialize for IndexSet<T, S>
+where
+    T: Ord + Serialize,
+    S: BuildHasher,
+{
+    fn serialize_sorted<Z>(&self, serializer: Z) -> Result<Z::Ok, Z::Error>
+    where
+        Z: Serializer,
+    {
+        serialize_sorted_seq(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<K, V, S> SortedSerialize for IndexMap<K, V, S>
+where
+    K: Ord + Serialize,
+    V: Serialize,
+    S: BuildHasher,
+{
+    fn serialize_sorted<Z>(&self, serializer: Z) -> Result<Z::Ok, Z::Error>
+    where
+        Z: Serializer,
+    {
+        serialize_sorted_map(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl_bounded_deserialize!(seq impl<T, S> IndexSet<T, S> => T where T: Eq + Hash, S: BuildHasher + Default);
+#[cfg(feature = "serde")]
+impl_bounded_deserialize!(map impl<K, V, S> IndexMap<K, V, S> => (K, V) where K: Eq + Hash, S: BuildHasher + Default);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stdx::collections::NonEmptySequence;
+
+    #[test]
+    fn test_non_empty_index_map() {
+        let index_map = IndexMap::from([(3, "c"), (1, "a"), (2, "b")]);
+
+        let non_empty_index_map = NonEmptyIndexMap::new(index_map.clone()).unwrap();
+        assert_eq!(non_empty_index_map.first(), (&3, &"c"));
+        assert_eq!(non_empty_index_map.last(), (&2, &"b"));
+        assert_eq!(non_empty_index_map.len().get(), 3);
+        assert_eq!(non_empty_index_map.get(&1), Some(&"a"));
+        assert_eq!(IndexMap::from(non_empty_index_map), index_map);
+
+        let non_empty_index_map = NonEmptyIndexMap::from_head_tail((1, "a"), [(2, "b")]);
+        assert_eq!(
+            non_empty_index_map.into_inner(),
+            IndexMap::from([(1, "a"), (2, "b")])
+        );
+
+        let empty = NonEmptyIndexMap::<i32, &str>::try_from(IndexMap::new());
+        assert!(matches!(empty, Err(SequenceContentError::Empty)));
+    }
+
+    #[test]
+    fn test_non_empty_index_set() {
+        let index_set = IndexSet::from([3, 1, 2]);
+
+        let non_empty_index_set = NonEmptyIndexSet::new(index_set.clone()).unwrap();
+        assert_eq!(non_empty_index_set.first(), &3);
+        assert_eq!(non_empty_index_set.minimum(), &1);
+        assert_eq!(IndexSet::from(non_empty_index_set), index_set);
+
+        let non_empty_index_set = NonEmptyIndexSet::singleton(1);
+        assert_eq!(non_empty_index_set.len().get(), 1);
+
+        let empty = NonEmptyIndexSet::<i32>::try_from(IndexSet::new());
+        assert!(matches!(empty, Err(SequenceContentError::Empty)));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_non_empty_index_collections_serde() {
+        use crate::stdx::collections::sorted_serde::Sorted;
+
+        let non_empty_index_map = NonEmptyIndexMap::new(IndexMap::from([("b".to_string(), 2), ("a".to_string(), 1)])).unwrap();
+
+        let serialized = serde_json::to_string(&non_empty_index_map).unwrap();
+        assert_eq!(serialized, r#"{"b":2,"a":1}"#);
+        let deserialized: NonEmptyIndexMap<String, i32> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, non_empty_index_map);
+        assert!(serde_json::from_str::<NonEmptyIndexMap<String, i32>>("{}").is_err());
+
+        let non_empty_index_set = NonEmptyIndexSet::new(IndexSet::from([3, 1, 2])).unwrap();
+
+        let serialized = serde_json::to_string(&non_empty_index_set).unwrap();
+        assert_eq!(serialized, "[3,1,2]");
+        let deserialized: NonEmptyIndexSet<i32> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, non_empty_index_set);
+        assert!(serde_json::from_str::<NonEmptyIndexSet<i32>>("[]").is_err());
+
+        assert_eq!(
+            serde_json::to_string(&Sorted(&non_empty_index_set)).unwrap(),
+            "[1,2,3]"
+        );
+        assert_eq!(
+            serde_json::to_string(&Sorted(&non_empty_index_map)).unwrap(),
+            r#"{"a":1,"b":2}"#
+        );
+    }
+}
@@ -0,0 +1,23 @@
+//! This module provides non-empty collections for third-party collection libraries, with the same
+//! constructors, accessors and serde support as the aliases over the standard collections.
+//!
+//! Each library is enabled through the cargo feature of the same name. When the `serde` feature is
+//! enabled, the `serde` feature of the enabled libraries is enabled as well.
+//!
+//! # Submodules
+//!
+//! - `arrayvec`: Provides `NonEmptyArrayVec` (requires the `arrayvec` feature).
+//! - `hashbrown`: Provides `NonEmptyHashMap` and `NonEmptyHashSet` over the `hashbrown`
+//!   collections (requires the `hashbrown` feature).
+//! - `indexmap`: Provides `NonEmptyIndexMap` and `NonEmptyIndexSet` (requires the `indexmap`
+//!   feature).
+//! - `smallvec`: Provides `NonEmptySmallVec` (requires the `smallvec` feature).
+
+#[cfg(feature = "arrayvec")]
+pub mod arrayvec;
+#[cfg(feature = "hashbrown")]
+pub mod hashbrown;
+#[cfg(feature = "indexmap")]
+pub mod indexmap;
+#[cfg(feature = "smallvec")]
+pub mod smallvec;
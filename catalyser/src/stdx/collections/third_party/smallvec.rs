@@ -0,0 +1,75 @@
+//! This module provides non-empty collections over the `smallvec` library, which store a few
+//! elements inline before spilling to the heap.
+//!
+//! # Usage Example
+//!
+//! ```rust
+//! use catalyser::stdx::collections::{third_party::smallvec::NonEmptySmallVec, NonEmptySequence};
+//! use smallvec::{smallvec, SmallVec};
+//!
+//! let non_empty_small_vec = NonEmptySmallVec::<[i32; 4]>::new(smallvec![1, 2, 3]).unwrap();
+//! assert_eq!(non_empty_small_vec.last(), &3);
+//! assert!(!non_empty_small_vec.spilled());
+//!
+//! assert!(NonEmptySmallVec::<[i32; 4]>::new(SmallVec::new()).is_err());
+//! ```
+
+#[cfg(feature = "serde")]
+use crate::stdx::collections::bounded_deserialize::impl_bounded_deserialize;
+use crate::stdx::{
+    collections::{impl_collection_conversion, impl_length, BoundedCollection, GrowableCollection, Length, NonEmptyCollection},
+    error::is_empty_sequence::SequenceContentError,
+};
+use smallvec::{Array, SmallVec};
+
+impl_length!(SmallVec<A> where A: Array);
+
+// SAFETY: a `SmallVec` spills to the heap rather than running out of capacity, and keeps every
+// element it is extended with.
+unsafe impl<A: Array> GrowableCollection for SmallVec<A> {}
+
+/// Specialized type for non-empty small vectors, storing up to the capacity of the array `A`
+/// inline (e.g. `NonEmptySmallVec<[T; 4]>`).
+pub type NonEmptySmallVec<A> = NonEmptyCollection<<A as Array>::Item, SmallVec<A>>;
+
+impl_collection_conversion!(impl<A> SmallVec<A> => A::Item where A: Array);
+
+#[cfg(feature = "serde")]
+impl_bounded_deserialize!(seq impl<A> SmallVec<A> => A::Item where A: Array);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stdx::collections::NonEmptySequence;
+    use smallvec::smallvec;
+
+    #[test]
+    fn test_non_empty_small_vec() {
+        let small_vec: SmallVec<[i32; 2]> = smallvec![3, 1, 2];
+
+        let non_empty_small_vec = NonEmptySmallVec::new(small_vec.clone()).unwrap();
+        assert_eq!(non_empty_small_vec.first(), &3);
+        assert_eq!(non_empty_small_vec.minimum(), &1);
+        assert_eq!(non_empty_small_vec.len().get(), 3);
+        assert!(non_empty_small_vec.spilled());
+        assert_eq!(SmallVec::from(non_empty_small_vec), small_vec);
+
+        let non_empty_small_vec = NonEmptySmallVec::<[i32; 2]>::from_head_tail(1, [2]);
+        assert_eq!(non_empty_small_vec.as_slice(), &[1, 2]);
+
+        let empty = NonEmptySmallVec::<[i32; 2]>::try_from(SmallVec::new());
+        assert!(matches!(empty, Err(SequenceContentError::Empty)));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_non_empty_small_vec_serde() {
+        let non_empty_small_vec = NonEmptySmallVec::<[i32; 2]>::new(smallvec![1, 2, 3]).unwrap();
+
+        let serialized = serde_json::to_string(&non_empty_small_vec).unwrap();
+        assert_eq!(serialized, "[1,2,3]");
+        let deserialized: NonEmptySmallVec<[i32; 2]> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, non_empty_small_vec);
+        assert!(serde_json::from_str::<NonEmptySmallVec<[i32; 2]>>("[]").is_err());
+    }
+}
@@ -0,0 +1,435 @@
+//! This module defines `UniqueVec`, a vector that preserves insertion order but rejects duplicate
+//! elements, and `NonEmptyUniqueVec`, its non-empty counterpart.
+//!
+//! Duplicates are rejected at construction, insertion and deserialization time, with a
+//! `SequenceContentError::Duplicate` error reporting the indices of the two equal elements.
+//!
+//! # Usage Example
+//!
+//! ```rust
+//! use catalyser::stdx::{
+//!     collections::unique_vec::{NonEmptyUniqueVec, UniqueVec},
+//!     error::is_empty_sequence::SequenceContentError,
+//! };
+//!
+//! let mut unique_vec = UniqueVec::new(vec!["b", "a"]).unwrap();
+//! assert!(unique_vec.push("c").is_ok());
+//! assert!(matches!(unique_vec.push("a"), Err(SequenceContentError::Duplicate { first: 1, second: 3 })));
+//! assert_eq!(unique_vec.into_inner(), vec!["b", "a", "c"]);
+//!
+//! let duplicates = UniqueVec::new(vec![1, 2, 1]);
+//! assert!(matches!(duplicates, Err(SequenceContentError::Duplicate { first: 0, second: 2 })));
+//!
+//! let non_empty_unique_vec = NonEmptyUniqueVec::try_from(vec![1, 2, 3]);
+//! assert!(non_empty_unique_vec.is_ok());
+//! ```
+
+#[cfg(feature = "serde")]
+use crate::stdx::collections::bounded_deserialize::BoundedDeserialize;
+use crate::stdx::{
+    collections::{BoundedCollection, Length, NonEmptyCollection},
+    error::is_empty_sequence::SequenceContentError,
+};
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Serialize};
+use std::{borrow::Borrow, collections::HashMap, hash::Hash, ops::Deref, slice, vec};
+
+/// A vector that preserves insertion order and contains no duplicate elements.
+///
+/// Uniqueness is checked in linear time at construction. Insertions compare the new element with
+/// every existing one, which suits the small ordered sets usually found in configuration files.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct UniqueVec<T>(Vec<T>);
+
+impl<T: Eq + Hash> UniqueVec<T> {
+    /// Creates a new unique vector.
+    ///
+    /// # Parameters
+    ///
+    /// - `vec`: The input vector.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Self)` if the vector contains no duplicate elements.
+    /// - `Err(SequenceContentError::Duplicate)` with the indices of the first pair of equal
+    ///   elements otherwise.
+    pub fn new(vec: Vec<T>) -> Result<Self, SequenceContentError> {
+        let mut indices = HashMap::with_capacity(vec.len());
+        for (index, element) in vec.iter().enumerate() {
+            if let Some(first) = indices.insert(element, index) {
+                return Err(SequenceContentError::Duplicate {
+                    first,
+                    second: index,
+                });
+            }
+        }
+        Ok(Self(vec))
+    }
+}
+
+impl<T: Eq> UniqueVec<T> {
+    /// Appends an element to the back of the vector, if it is not already present.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The element to append.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the element has been appended.
+    /// - `Err(SequenceContentError::Duplicate)` with the index of the equal element and the index
+    ///   the new one would have had otherwise. The vector is left unchanged.
+    pub fn push(&mut self, value: T) -> Result<(), SequenceContentError> {
+        self.check_absent(&value, self.0.len())?;
+        self.0.push(value);
+        Ok(())
+    }
+
+    /// Inserts an element at position `index`, if it is not already present.
+    ///
+    /// # Parameters
+    ///
+    /// - `index`: The position of the new element.
+    /// - `value`: The element to insert.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the element has been inserted.
+    /// - `Err(SequenceContentError::Duplicate)` with the index of the equal element and `index`
+    ///   otherwise. The vector is left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, value: T) -> Result<(), SequenceContentError> {
+        self.check_absent(&value, index)?;
+        self.0.insert(index, value);
+        Ok(())
+    }
+
+    fn check_absent(&self, value: &T, index: usize) -> Result<(), SequenceContentError> {
+        match self.0.iter().position(|it| it == value) {
+            Some(first) => Err(SequenceContentError::Duplicate {
+                first,
+                second: index,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T> UniqueVec<T> {
+    /// Creates a new unique vector.
+    ///
+    /// # Parameters
+    ///
+    /// - `vec`: The input vector.
+    ///
+    /// # Returns
+    ///
+    /// - `Self`: A new unique vector.
+    ///
+    /// # Safety
+    ///
+    /// This function assumes that the vector contains no duplicate elements.
+    pub unsafe fn new_unchecked(vec: Vec<T>) -> Self {
+        Self(vec)
+    }
+
+    /// Returns the inner vector.
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+
+    /// Returns a reference to the inner vector.
+    pub fn as_inner(&self) -> &Vec<T> {
+        &self.0
+    }
+
+    /// Removes the last element and returns it, or `None` if the vector is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.0.pop()
+    }
+
+    /// Removes and returns the element at position `index`, shifting all elements after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        self.0.remove(index)
+    }
+
+    /// Retains only the elements for which `f` returns `true`, preserving their order.
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.0.retain(f)
+    }
+}
+
+impl<T> Default for UniqueVec<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T> Deref for UniqueVec<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> AsRef<Vec<T>> for UniqueVec<T> {
+    fn as_ref(&self) -> &Vec<T> {
+        &self.0
+    }
+}
+
+impl<T> AsRef<[T]> for UniqueVec<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T> Borrow<Vec<T>> for UniqueVec<T> {
+    fn borrow(&self) -> &Vec<T> {
+        &self.0
+    }
+}
+
+impl<T> IntoIterator for UniqueVec<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a UniqueVec<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<T> Length for UniqueVec<T> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<T: Eq + Hash> TryFrom<Vec<T>> for UniqueVec<T> {
+    type Error = SequenceContentError;
+
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        Self::new(vec)
+    }
+}
+
+impl<T> From<UniqueVec<T>> for Vec<T> {
+    fn from(unique_vec: UniqueVec<T>) -> Self {
+        unique_vec.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> Deserialize<'de> for UniqueVec<T>
+where
+    T: Eq + Hash + Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Self::deserialize_bounded(deserializer, 0, usize::MAX)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> BoundedDeserialize<'de> for UniqueVec<T>
+where
+    T: Eq + Hash + Deserialize<'de>,
+{
+    fn deserialize_bounded<D>(deserializer: D, min: usize, max: usize) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let vec = Vec::deserialize_bounded(deserializer, min, max)?;
+        Self::new(vec).map_err(D::Error::custom)
+    }
+}
+
+/// Specialized type for non-empty unique vectors.
+pub type NonEmptyUniqueVec<T> = NonEmptyCollection<T, UniqueVec<T>>;
+
+impl<T: Eq + Hash, const MIN: usize, const MAX: usize> TryFrom<Vec<T>> for BoundedCollection<T, UniqueVec<T>, MIN, MAX> {
+    type Error = SequenceContentError;
+
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        Self::new(UniqueVec::new(vec)?)
+    }
+}
+
+impl<T: Eq> NonEmptyUniqueVec<T> {
+    /// Appends an element to the back of the vector, if it is not already present.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the element has been appended.
+    /// - `Err(SequenceContentError::Duplicate)` if an equal element is already present.
+    pub fn push(&mut self, value: T) -> Result<(), SequenceContentError> {
+        self.0.push(value)
+    }
+
+    /// Inserts an element at position `index`, if it is not already present.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the element has been inserted.
+    /// - `Err(SequenceContentError::Duplicate)` if an equal element is already present.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, value: T) -> Result<(), SequenceContentError> {
+        self.0.insert(index, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stdx::collections::{NonEmptySequence, NonEmptyVec};
+
+    #[test]
+    fn test_unique_vec_new() {
+        let unique_vec = UniqueVec::new(vec![3, 1, 2]);
+        assert_eq!(unique_vec.unwrap().into_inner(), vec![3, 1, 2]);
+
+        let unique_vec = UniqueVec::new(vec![3, 1, 2, 1, 3]);
+        assert!(matches!(
+            unique_vec,
+            Err(SequenceContentError::Duplicate {
+                first: 1,
+                second: 3
+            })
+        ));
+
+        let unique_vec = UniqueVec::<i32>::new(Vec::new());
+        assert!(unique_vec.is_ok());
+
+        let unique_vec: Result<UniqueVec<i32>, _> = vec![1, 1].try_into();
+        assert!(matches!(
+            unique_vec,
+            Err(SequenceContentError::Duplicate {
+                first: 0,
+                second: 1
+            })
+        ));
+
+        let unique_vec = unsafe { UniqueVec::new_unchecked(vec![1, 2]) };
+        assert_eq!(Vec::from(unique_vec), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_unique_vec_mutation() {
+        let mut unique_vec = UniqueVec::new(vec![1, 2]).unwrap();
+
+        assert!(unique_vec.push(3).is_ok());
+        assert!(matches!(
+            unique_vec.push(1),
+            Err(SequenceContentError::Duplicate {
+                first: 0,
+                second: 3
+            })
+        ));
+        assert!(unique_vec.insert(0, 0).is_ok());
+        assert!(matches!(
+            unique_vec.insert(1, 3),
+            Err(SequenceContentError::Duplicate {
+                first: 3,
+                second: 1
+            })
+        ));
+        assert_eq!(unique_vec.as_inner(), &vec![0, 1, 2, 3]);
+
+        assert_eq!(unique_vec.remove(1), 1);
+        assert_eq!(unique_vec.pop(), Some(3));
+        unique_vec.retain(|it| *it != 0);
+        assert_eq!(unique_vec.len(), 1);
+        assert!(unique_vec.contains(&2));
+        assert_eq!((&unique_vec).into_iter().collect::<Vec<_>>(), vec![&2]);
+    }
+
+    #[test]
+    fn test_non_empty_unique_vec() {
+        let mut non_empty_unique_vec = NonEmptyUniqueVec::try_from(vec!["a", "b"]).unwrap();
+        assert_eq!(non_empty_unique_vec.first(), &"a");
+        assert_eq!(non_empty_unique_vec.len().get(), 2);
+
+        assert!(non_empty_unique_vec.push("c").is_ok());
+        assert!(non_empty_unique_vec.push("a").is_err());
+        assert!(non_empty_unique_vec.insert(0, "z").is_ok());
+
+        let unique_vec = UniqueVec::from(non_empty_unique_vec.clone());
+        assert_eq!(
+            NonEmptyUniqueVec::try_from(unique_vec).unwrap(),
+            non_empty_unique_vec
+        );
+
+        let non_empty_vec = NonEmptyVec::from(non_empty_unique_vec);
+        assert_eq!(non_empty_vec.into_inner(), vec!["z", "a", "b", "c"]);
+
+        let empty = NonEmptyUniqueVec::<i32>::try_from(Vec::new());
+        assert!(matches!(empty, Err(SequenceContentError::Empty)));
+
+        let duplicates = NonEmptyUniqueVec::try_from(vec![1, 1]);
+        assert!(matches!(
+            duplicates,
+            Err(SequenceContentError::Duplicate {
+                first: 0,
+                second: 1
+            })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_unique_vec_serde() {
+        let unique_vec = UniqueVec::new(vec![3, 1, 2]).unwrap();
+
+        let serialized = serde_json::to_string(&unique_vec).unwrap();
+        assert_eq!(serialized, "[3,1,2]");
+
+        let deserialized: UniqueVec<i32> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, unique_vec);
+
+        let deserialized: Result<UniqueVec<i32>, _> = serde_json::from_str("[3,1,3]");
+        assert!(deserialized
+            .unwrap_err()
+            .to_string()
+            .contains("indices: 0 and 2"));
+
+        let deserialized: NonEmptyUniqueVec<i32> = serde_json::from_str("[3,1,2]").unwrap();
+        assert_eq!(deserialized.into_inner(), unique_vec);
+
+        let deserialized: Result<NonEmptyUniqueVec<i32>, _> = serde_json::from_str("[]");
+        assert!(deserialized.is_err());
+        let deserialized: Result<NonEmptyUniqueVec<i32>, _> = serde_json::from_str("[1,1]");
+        assert!(deserialized.is_err());
+    }
+}
@@ -0,0 +1,375 @@
+//! This module defines `ValidatedCollection`, a collection wrapper ensuring that every element
+//! satisfies the rules of an `ElementValidator`, in the same way `ValidatedString` does for the
+//! content of strings.
+//!
+//! Every string validator implementing `StringContentValidator` is an element validator for
+//! `String`, and every bounded integer of `primitive_number` (e.g. `BoundedI32<MIN, MAX>`) is an
+//! element validator for its primitive type.
+//!
+//! The wrapper implements [Length] and `Default` (an empty collection is valid), so it can itself
+//! be wrapped in a [NonEmptyCollection](crate::stdx::collections::NonEmptyCollection) or a
+//! [BoundedCollection](crate::stdx::collections::BoundedCollection).
+//!
+//! # Usage Example
+//!
+//! ```rust
+//! use catalyser::stdx::{
+//!     collections::{validated_collection::ValidatedVec, NonEmptyCollection},
+//!     primitive_number::BoundedI32,
+//!     string::NonBlankValidator,
+//! };
+//!
+//! let percentages = ValidatedVec::<i32, BoundedI32<0, 100>>::new(vec![0, 50, 100]);
+//! assert!(percentages.is_ok());
+//!
+//! let percentages = ValidatedVec::<i32, BoundedI32<0, 100>>::new(vec![0, 150, -1]);
+//! assert_eq!(
+//!     percentages.unwrap_err().to_string(),
+//!     "collection contains invalid elements (index 1: 150 is too high (range: 0..100); index 2: -1 is too low (range: 0..100))"
+//! );
+//!
+//! let tags = NonEmptyCollection::new(ValidatedVec::<String, NonBlankValidator>::new(vec!["rust".to_string()]).unwrap());
+//! assert!(tags.is_ok());
+//! ```
+
+#[cfg(feature = "serde")]
+use crate::stdx::collections::bounded_deserialize::BoundedDeserialize;
+use crate::stdx::{collections::Length, error::invalid_element::ElementContentError, string::StringContentValidator};
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use std::fmt::Display;
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::Deref,
+};
+
+/// A trait for validating the elements of a `ValidatedCollection`.
+///
+/// This trait is implemented by types that define rules for the elements of type `T`, the same way
+/// `StringContentValidator` does for the content of strings.
+pub trait ElementValidator<T> {
+    /// The error reported for an invalid element.
+    type Error;
+
+    /// Validates a single element.
+    ///
+    /// # Parameters
+    ///
+    /// - `element`: The element to validate.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())`: If the element meets the validation criteria.
+    /// - `Err(Self::Error)`: If the element fails validation, indicating the error type.
+    fn validate(element: &T) -> Result<(), Self::Error>;
+}
+
+impl<V: StringContentValidator> ElementValidator<String> for V {
+    type Error = V::Error;
+
+    fn validate(element: &String) -> Result<(), Self::Error> {
+        <V as StringContentValidator>::validate(element)
+    }
+}
+
+/// A wrapper around a collection that ensures each of its elements adheres to the rules defined by
+/// the `ElementValidator` `V`.
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct ValidatedCollection<C, V>(C, PhantomData<V>);
+
+impl<C, V> ValidatedCollection<C, V>
+where
+    C: IntoIterator,
+    for<'a> &'a C: IntoIterator<Item = &'a C::Item>,
+    V: ElementValidator<C::Item>,
+{
+    /// Creates a new `ValidatedCollection` by validating every element of the provided collection.
+    ///
+    /// # Parameters
+    ///
+    /// - `collection`: The input collection to validate and wrap.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Self)`: If every element passes validation.
+    /// - `Err(ElementContentError::Invalid)`: If some elements fail validation, with the index and
+    ///   the error of each of them.
+    pub fn new(collection: C) -> Result<Self, ElementContentError<V::Error>> {
+        let errors: Vec<_> = (&collection)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, element)| V::validate(element).err().map(|error| (index, error)))
+            .collect();
+
+        if errors.is_empty() {
+            Ok(Self(collection, PhantomData))
+        } else {
+            Err(ElementContentError::Invalid(errors))
+        }
+    }
+}
+
+impl<C, V> ValidatedCollection<C, V> {
+    /// Creates a new `ValidatedCollection` without performing any validation.
+    ///
+    /// # Parameters
+    ///
+    /// - `collection`: The input collection to wrap.
+    ///
+    /// # Safety
+    ///
+    /// This method is unsafe because it assumes the caller has ensured every element is valid
+    /// without verification.
+    pub unsafe fn new_unchecked(collection: C) -> Self {
+        Self(collection, PhantomData)
+    }
+
+    /// Consumes the `ValidatedCollection` and returns the inner collection.
+    pub fn into_inner(self) -> C {
+        self.0
+    }
+
+    /// Returns a reference to the inner collection.
+    pub fn as_inner(&self) -> &C {
+        &self.0
+    }
+}
+
+impl<C, V> Deref for ValidatedCollection<C, V> {
+    type Target = C;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<C, V> AsRef<C> for ValidatedCollection<C, V> {
+    fn as_ref(&self) -> &C {
+        &self.0
+    }
+}
+
+impl<C, V> Borrow<C> for ValidatedCollection<C, V> {
+    fn borrow(&self) -> &C {
+        &self.0
+    }
+}
+
+impl<C: IntoIterator, V> IntoIterator for ValidatedCollection<C, V> {
+    type Item = C::Item;
+    type IntoIter = C::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, C, V> IntoIterator for &'a ValidatedCollection<C, V>
+where
+    &'a C: IntoIterator,
+{
+    type Item = <&'a C as IntoIterator>::Item;
+    type IntoIter = <&'a C as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self.0).into_iter()
+    }
+}
+
+impl<C: Length, V> Length for ValidatedCollection<C, V> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<C: Default, V> Default for ValidatedCollection<C, V> {
+    /// Creates an empty collection, which is trivially valid.
+    fn default() -> Self {
+        Self(C::default(), PhantomData)
+    }
+}
+
+impl<C: Debug, V> Debug for ValidatedCollection<C, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("ValidatedCollection").field(&self.0).finish()
+    }
+}
+
+impl<C: Clone, V> Clone for ValidatedCollection<C, V> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<C: PartialEq, V> PartialEq for ValidatedCollection<C, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<C: Eq, V> Eq for ValidatedCollection<C, V> {}
+
+impl<C: PartialOrd, V> PartialOrd for ValidatedCollection<C, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<C: Ord, V> Ord for ValidatedCollection<C, V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<C: Hash, V> Hash for ValidatedCollection<C, V> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C, V> Deserialize<'de> for ValidatedCollection<C, V>
+where
+    C: IntoIterator + Deserialize<'de>,
+    for<'a> &'a C: IntoIterator<Item = &'a C::Item>,
+    V: ElementValidator<C::Item>,
+    V::Error: Display,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let collection = C::deserialize(deserializer)?;
+        Self::new(collection).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C, V> BoundedDeserialize<'de> for ValidatedCollection<C, V>
+where
+    C: IntoIterator + BoundedDeserialize<'de>,
+    for<'a> &'a C: IntoIterator<Item = &'a C::Item>,
+    V: ElementValidator<C::Item>,
+    V::Error: Display,
+{
+    fn deserialize_bounded<D>(deserializer: D, min: usize, max: usize) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let collection = C::deserialize_bounded(deserializer, min, max)?;
+        Self::new(collection).map_err(D::Error::custom)
+    }
+}
+
+/// Specialized type for vectors whose elements are validated by `V`.
+pub type ValidatedVec<T, V> = ValidatedCollection<Vec<T>, V>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stdx::{
+        collections::{NonEmptyCollection, NonEmptyVec},
+        error::{is_empty_or_blank_string::StringContentError, is_empty_sequence::SequenceContentError, out_of_bound::OutOfBoundsError},
+        primitive_number::BoundedI32,
+        string::NonBlankValidator,
+    };
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_validated_collection_new() {
+        let strings = ValidatedVec::<String, NonBlankValidator>::new(vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(
+            strings.unwrap().into_inner(),
+            vec!["a".to_string(), "b".to_string()]
+        );
+
+        let strings = ValidatedVec::<String, NonBlankValidator>::new(vec!["a".to_string(), " ".to_string(), "".to_string()]);
+        let Err(ElementContentError::Invalid(errors)) = strings else {
+            panic!("expected invalid elements");
+        };
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(&errors[0], (1, StringContentError::Blank(value)) if value == " "));
+        assert!(matches!(&errors[1], (2, StringContentError::Blank(value)) if value.is_empty()));
+
+        let numbers = ValidatedCollection::<BTreeSet<i32>, BoundedI32<0, 10>>::new(BTreeSet::from([11, 1, -1]));
+        let Err(ElementContentError::Invalid(errors)) = numbers else {
+            panic!("expected invalid elements");
+        };
+        assert!(matches!(
+            errors[..],
+            [
+                (0, OutOfBoundsError::Low(0, 10, -1)),
+                (2, OutOfBoundsError::High(0, 10, 11))
+            ]
+        ));
+
+        let empty = ValidatedVec::<i32, BoundedI32<0, 10>>::new(Vec::new());
+        assert!(empty.is_ok());
+    }
+
+    #[test]
+    fn test_validated_collection_new_unchecked() {
+        let numbers = unsafe { ValidatedVec::<i32, BoundedI32<0, 10>>::new_unchecked(vec![42]) };
+        assert_eq!(numbers.as_inner(), &vec![42]);
+    }
+
+    #[test]
+    fn test_validated_collection_borrow() {
+        let numbers = ValidatedVec::<i32, BoundedI32<0, 10>>::new(vec![1, 2, 3]).unwrap();
+
+        assert_eq!(numbers.len(), 3);
+        assert_eq!(numbers.as_ref(), &vec![1, 2, 3]);
+        assert_eq!((&numbers).into_iter().sum::<i32>(), 6);
+        assert_eq!(
+            numbers.clone().into_iter().collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(format!("{numbers:?}"), "ValidatedCollection([1, 2, 3])");
+    }
+
+    #[test]
+    fn test_validated_collection_non_empty() {
+        let numbers = ValidatedVec::<i32, BoundedI32<0, 10>>::new(vec![1]).unwrap();
+        let non_empty = NonEmptyCollection::new(numbers);
+        assert_eq!(non_empty.unwrap().len().get(), 1);
+
+        let empty = NonEmptyCollection::new(ValidatedVec::<i32, BoundedI32<0, 10>>::default());
+        assert!(matches!(empty, Err(SequenceContentError::Empty)));
+
+        let elements = NonEmptyVec::new(vec![1, 2]).unwrap();
+        let validated = ValidatedVec::<i32, BoundedI32<0, 1>>::new(elements.into_inner());
+        assert!(validated.is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_validated_collection_serde() {
+        let strings = ValidatedVec::<String, NonBlankValidator>::new(vec!["a".to_string(), "b".to_string()]).unwrap();
+
+        let serialized = serde_json::to_string(&strings).unwrap();
+        assert_eq!(serialized, r#"["a","b"]"#);
+
+        let deserialized: ValidatedVec<String, NonBlankValidator> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, strings);
+
+        let deserialized: Result<ValidatedVec<String, NonBlankValidator>, _> = serde_json::from_str(r#"["a"," "]"#);
+        assert!(deserialized
+            .unwrap_err()
+            .to_string()
+            .contains("index 1: string is blank"));
+
+        let deserialized: Result<NonEmptyCollection<String, ValidatedVec<String, NonBlankValidator>>, _> = serde_json::from_str("[]");
+        assert!(deserialized.is_err());
+    }
+}
@@ -0,0 +1,55 @@
+//! This module contains an enumeration `ElementContentError` for representing possible errors
+//! related to the validation of the elements of a collection. Each failing element is reported
+//! with its index and the error returned by the element validator.
+//!
+//! The module also provides implementations of the `Debug`, `Display` and `Error` traits for
+//! `ElementContentError`, enabling error representation in different formats and its use with the
+//! `?` operator.
+
+use std::{
+    error::Error,
+    fmt::{Debug, Display, Formatter},
+};
+
+/// Represents possible errors related to the validation of the elements of a collection.
+pub enum ElementContentError<E> {
+    /// Indicates that some elements are invalid. Includes the `(index, error)` pair of every failing
+    /// element, in iteration order.
+    Invalid(Vec<(usize, E)>),
+}
+
+impl<E: Debug> Debug for ElementContentError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ElementContentError::Invalid(errors) => {
+                write!(f, "Invalid(")?;
+                for (position, (index, error)) in errors.iter().enumerate() {
+                    if position > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "(index = {}, error = {:?})", index, error)?;
+                }
+                write!(f, ")")
+            },
+        }
+    }
+}
+
+impl<E: Display> Display for ElementContentError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ElementContentError::Invalid(errors) => {
+                write!(f, "collection contains invalid elements (")?;
+                for (position, (index, error)) in errors.iter().enumerate() {
+                    if position > 0 {
+                        write!(f, "; ")?;
+                    }
+                    write!(f, "index {}: {}", index, error)?;
+                }
+                write!(f, ")")
+            },
+        }
+    }
+}
+
+impl<E: Debug + Display> Error for ElementContentError<E> {}
@@ -1,18 +1,87 @@
 //! This module contains an enumeration `StringContentError` for representing possible errors
-//! related to validation of string content. This includes checks for empty strings or strings that
-//! consist only of blank spaces.
+//! related to validation of string content. This includes checks for empty strings, strings that
+//! consist only of blank spaces, whose length is out of the expected bounds or which do not match
+//! a pattern, the aggregated errors of combined validators, and the errors of custom validators.
 //!
-//! The module also provides implementations of the `Debug` and `Display` traits for
-//! `StringContentError`, enabling error representation in different formats.
+//! The module also provides implementations of the `Debug`, `Display` and `Error` traits for
+//! `StringContentError`, enabling error representation in different formats and its use with the
+//! `?` operator.
 
-use std::fmt::{Debug, Display, Formatter};
+use std::{
+    error::Error,
+    fmt::{Debug, Display, Formatter},
+};
 
 /// Represents possible errors related to string content validation.
+///
+/// New variants may be added as new validators are provided, so matches on this enumeration must
+/// include a wildcard arm outside of this crate.
+#[non_exhaustive]
 pub enum StringContentError {
     /// Indicates the string is empty.
     Empty,
     /// Indicates the string contains only blank spaces. Includes the original string.
     Blank(String),
+    /// Indicates the string is shorter than the minimum length.
+    TooShort {
+        /// The minimum length.
+        min: usize,
+        /// The actual length.
+        actual: usize,
+    },
+    /// Indicates the string is longer than the maximum length.
+    TooLong {
+        /// The maximum length.
+        max: usize,
+        /// The actual length.
+        actual: usize,
+    },
+    /// Indicates the string does not match the regular expression of a `PatternValidator`.
+    PatternMismatch {
+        /// The regular expression the string must match.
+        pattern: &'static str,
+        /// The original string.
+        value: String,
+    },
+    /// Indicates the string violates several rules of an `And` validator. Includes the error of
+    /// every violated rule.
+    Multiple(Vec<StringContentError>),
+    /// Indicates the string satisfies none of the rules of an `Or` validator. Includes the error of
+    /// every rule.
+    NoMatch(Vec<StringContentError>),
+    /// Indicates the string satisfies the rule negated by a `Not` validator. Includes the original
+    /// string.
+    Negated(String),
+    /// Indicates the string violates the rule of a custom validator. Includes the error of the
+    /// validator.
+    Custom(Box<dyn Error + Send + Sync>),
+}
+
+impl StringContentError {
+    /// Creates a `StringContentError::Custom` from the error of a custom validator.
+    ///
+    /// # Parameters
+    ///
+    /// - `error`: The error of the validator, or a message describing it.
+    pub fn custom<E: Into<Box<dyn Error + Send + Sync>>>(error: E) -> Self {
+        StringContentError::Custom(error.into())
+    }
+}
+
+/// Writes the errors separated by `separator`, with the given formatting function.
+fn write_errors(
+    f: &mut Formatter<'_>,
+    errors: &[StringContentError],
+    separator: &str,
+    write_error: fn(&StringContentError, &mut Formatter<'_>) -> std::fmt::Result,
+) -> std::fmt::Result {
+    for (position, error) in errors.iter().enumerate() {
+        if position > 0 {
+            write!(f, "{}", separator)?;
+        }
+        write_error(error, f)?;
+    }
+    Ok(())
 }
 
 impl Debug for StringContentError {
@@ -20,19 +89,63 @@ impl Debug for StringContentError {
         match self {
             StringContentError::Empty => write!(f, "Empty"),
             StringContentError::Blank(value) => write!(f, "Blank(value = `{}`)", value.escape_debug()),
+            StringContentError::TooShort { min, actual } => write!(f, "TooShort(min = {min}, actual = {actual})"),
+            StringContentError::TooLong { max, actual } => write!(f, "TooLong(max = {max}, actual = {actual})"),
+            StringContentError::PatternMismatch { pattern, value } => write!(
+                f,
+                "PatternMismatch(pattern = `{}`, value = `{}`)",
+                pattern.escape_debug(),
+                value.escape_debug()
+            ),
+            StringContentError::Multiple(errors) => {
+                write!(f, "Multiple(")?;
+                write_errors(f, errors, ", ", Debug::fmt)?;
+                write!(f, ")")
+            },
+            StringContentError::NoMatch(errors) => {
+                write!(f, "NoMatch(")?;
+                write_errors(f, errors, ", ", Debug::fmt)?;
+                write!(f, ")")
+            },
+            StringContentError::Negated(value) => write!(f, "Negated(value = `{}`)", value.escape_debug()),
+            StringContentError::Custom(error) => write!(f, "Custom({:?})", error),
         }
     }
 }
 
 impl Display for StringContentError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                StringContentError::Empty => "string is empty".to_string(),
-                StringContentError::Blank(value) => format!("string is blank (content: `{}`)", value),
-            }
-        )
+        match self {
+            StringContentError::Empty => write!(f, "string is empty"),
+            StringContentError::Blank(value) => write!(f, "string is blank (content: `{}`)", value),
+            StringContentError::TooShort { min, actual } => write!(f, "string is too short (minimum: {min}, actual: {actual})"),
+            StringContentError::TooLong { max, actual } => write!(f, "string is too long (maximum: {max}, actual: {actual})"),
+            StringContentError::PatternMismatch { pattern, value } => write!(
+                f,
+                "string does not match the pattern `{}` (content: `{}`)",
+                pattern, value
+            ),
+            StringContentError::Multiple(errors) => {
+                write!(f, "string violates several rules (")?;
+                write_errors(f, errors, "; ", Display::fmt)?;
+                write!(f, ")")
+            },
+            StringContentError::NoMatch(errors) => {
+                write!(f, "string satisfies none of the alternatives (")?;
+                write_errors(f, errors, "; ", Display::fmt)?;
+                write!(f, ")")
+            },
+            StringContentError::Negated(value) => write!(f, "string satisfies a negated rule (content: `{}`)", value),
+            StringContentError::Custom(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for StringContentError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StringContentError::Custom(error) => error.source(),
+            _ => None,
+        }
     }
 }
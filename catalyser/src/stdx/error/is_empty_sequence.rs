@@ -1,21 +1,57 @@
 //! This module contains an enumeration `SequenceContentError` for representing possible errors
-//! related to the validation of sequences. This includes checks for cases such as empty sequences.
+//! related to the validation of sequences. This includes checks for cases such as empty sequences,
+//! sequences whose length is out of the expected bounds, sequences holding duplicate elements or
+//! unsorted sequences.
 //!
-//! The module also provides implementations of the `Debug` and `Display` traits for
-//! `SequenceContentError`, enabling error representation in different formats.
+//! The module also provides implementations of the `Debug`, `Display` and `Error` traits for
+//! `SequenceContentError`, enabling error representation in different formats and its use with the
+//! `?` operator.
 
-use std::fmt::{Debug, Display, Formatter};
+use std::{
+    error::Error,
+    fmt::{Debug, Display, Formatter},
+};
 
 /// Represents possible errors related to sequence validation.
 pub enum SequenceContentError {
     /// Indicates that the sequence is empty.
     Empty,
+    /// Indicates that the sequence holds fewer elements than the minimum.
+    TooFew {
+        /// The minimum number of elements.
+        min: usize,
+        /// The actual number of elements.
+        actual: usize,
+    },
+    /// Indicates that the sequence holds more elements than the maximum.
+    TooMany {
+        /// The maximum number of elements.
+        max: usize,
+        /// The actual number of elements.
+        actual: usize,
+    },
+    /// Indicates that the sequence holds two equal elements.
+    Duplicate {
+        /// The index of the first occurrence of the element.
+        first: usize,
+        /// The index of the duplicate occurrence of the element.
+        second: usize,
+    },
+    /// Indicates that the sequence is not sorted.
+    Unsorted {
+        /// The index of the first element out of order with the previous one.
+        index: usize,
+    },
 }
 
 impl Debug for SequenceContentError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             SequenceContentError::Empty => write!(f, "Empty"),
+            SequenceContentError::TooFew { min, actual } => write!(f, "TooFew(min = {min}, actual = {actual})"),
+            SequenceContentError::TooMany { max, actual } => write!(f, "TooMany(max = {max}, actual = {actual})"),
+            SequenceContentError::Duplicate { first, second } => write!(f, "Duplicate(first = {first}, second = {second})"),
+            SequenceContentError::Unsorted { index } => write!(f, "Unsorted(index = {index})"),
         }
     }
 }
@@ -27,7 +63,13 @@ impl Display for SequenceContentError {
             "{}",
             match self {
                 SequenceContentError::Empty => "sequence is empty".to_string(),
+                SequenceContentError::TooFew { min, actual } => format!("sequence has too few elements (minimum: {min}, actual: {actual})"),
+                SequenceContentError::TooMany { max, actual } => format!("sequence has too many elements (maximum: {max}, actual: {actual})"),
+                SequenceContentError::Duplicate { first, second } => format!("sequence contains duplicate elements (indices: {first} and {second})"),
+                SequenceContentError::Unsorted { index } => format!("sequence is not sorted (element at index {index} is out of order)"),
             }
         )
     }
 }
+
+impl Error for SequenceContentError {}
@@ -5,10 +5,12 @@
 //!
 //! # Submodules
 //!
+//! - `invalid_element`: Handles errors arising from invalid elements of a collection.
 //! - `is_empty_or_blank_string`: Handles errors arising from blank or empty strings.
 //! - `is_empty_sequence`: Handles errors arising from empty sequences.
 //! - `out_of_bound`: Handles errors arising from out-of-bound numbers.
 
+pub mod invalid_element;
 pub mod is_empty_or_blank_string;
 pub mod is_empty_sequence;
 pub mod out_of_bound;
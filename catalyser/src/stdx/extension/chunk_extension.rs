@@ -0,0 +1,83 @@
+//! This module provides fixed-size chunking operations on slices, yielding non-empty chunks.
+//!
+//! Since the chunk size is a `NonZeroUsize`, every chunk holds at least one element, so the chunks
+//! are yielded as `NonEmptySlice` (e.g. a batch of writes that can never be empty).
+//!
+//! # Usage Example
+//!
+//! ```rust
+//! use catalyser::stdx::extension::chunk_extension::NonEmptyChunkSlice;
+//! use std::num::NonZeroUsize;
+//!
+//! let rows = [1, 2, 3, 4, 5];
+//! let size = NonZeroUsize::new(2).unwrap();
+//!
+//! let batches: Vec<usize> = rows.chunks_non_empty(size).map(|batch| batch.len().get()).collect();
+//! assert_eq!(batches, vec![2, 2, 1]);
+//! ```
+
+use crate::stdx::collections::non_empty_slice::{NonEmptyChunks, NonEmptyWindows};
+use std::num::NonZeroUsize;
+
+/// An interface for splitting a slice into non-empty chunks or windows.
+pub trait NonEmptyChunkSlice<T> {
+    /// Returns an iterator over non-empty chunks of `size` elements, the last chunk holding the
+    /// remaining elements if `size` does not divide the length of the slice. The iterator is
+    /// empty if the slice is.
+    ///
+    /// # Parameters
+    /// - `size`: The number of elements of each chunk.
+    fn chunks_non_empty(&self, size: NonZeroUsize) -> NonEmptyChunks<'_, T>;
+
+    /// Returns an iterator over all the overlapping windows of `size` elements. The iterator is
+    /// empty if the slice is shorter than `size`.
+    ///
+    /// # Parameters
+    /// - `size`: The number of elements of each window.
+    fn windows_non_empty(&self, size: NonZeroUsize) -> NonEmptyWindows<'_, T>;
+}
+
+impl<T> NonEmptyChunkSlice<T> for [T] {
+    fn chunks_non_empty(&self, size: NonZeroUsize) -> NonEmptyChunks<'_, T> {
+        NonEmptyChunks::new(self, size)
+    }
+
+    fn windows_non_empty(&self, size: NonZeroUsize) -> NonEmptyWindows<'_, T> {
+        NonEmptyWindows::new(self, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stdx::collections::non_empty_slice::NonEmptySlice;
+
+    #[test]
+    fn test_chunks_non_empty() {
+        let data = [1, 2, 3, 4, 5];
+        let chunks: Vec<&[i32]> = data
+            .chunks_non_empty(NonZeroUsize::new(2).unwrap())
+            .map(NonEmptySlice::as_slice)
+            .collect();
+        assert_eq!(chunks, vec![&[1, 2][..], &[3, 4], &[5]]);
+
+        let empty: &[i32] = &[];
+        assert_eq!(empty.chunks_non_empty(NonZeroUsize::MIN).len(), 0);
+    }
+
+    #[test]
+    fn test_windows_non_empty() {
+        let data = [1, 2, 3, 4];
+        let windows: Vec<i32> = data
+            .windows_non_empty(NonZeroUsize::new(3).unwrap())
+            .map(|window| *window.last())
+            .collect();
+        assert_eq!(windows, vec![3, 4]);
+
+        assert_eq!(
+            data.windows_non_empty(NonZeroUsize::new(5).unwrap())
+                .count(),
+            0
+        );
+    }
+}
@@ -0,0 +1,237 @@
+//! This module provides grouping and partitioning operations on iterators and slices, producing
+//! non-empty groups.
+//!
+//! Every group produced by these operations holds at least one element by construction, so the
+//! groups are returned as `NonEmptyVec` (or `NonEmptySlice`) rather than plain vectors that would
+//! have to be checked or unwrapped afterward.
+//!
+//! # Usage Example
+//!
+//! ```rust
+//! use catalyser::stdx::{collections::NonEmptySequence, extension::group_extension::NonEmptyGroupIterator};
+//!
+//! let words = ["apple", "avocado", "banana", "blueberry", "cherry"];
+//!
+//! let by_initial = words.into_iter().group_by_key(|word| word.chars().next());
+//! assert_eq!(by_initial[&Some('b')].first(), &"banana");
+//! assert_eq!(by_initial[&Some('c')].len().get(), 1);
+//! ```
+
+use crate::stdx::collections::{non_empty_slice::NonEmptySlice, NonEmptySequence, NonEmptyVec};
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+};
+
+/// The result of a partition: the non-empty group of elements matching the predicate and the
+/// non-empty group of the others, each `None` if there are no such elements.
+pub type NonEmptyPartition<T> = (Option<NonEmptyVec<T>>, Option<NonEmptyVec<T>>);
+
+/// An interface for grouping the elements of an iterator into non-empty groups.
+pub trait NonEmptyGroupIterator: Iterator + Sized {
+    /// Groups the elements by the key returned by `key`, keeping their relative order within each
+    /// group.
+    ///
+    /// # Parameters
+    /// - `key`: The function returning the key of each element.
+    ///
+    /// # Returns
+    /// - A map from each key to the non-empty group of elements having this key.
+    fn group_by_key<K, F>(self, key: F) -> HashMap<K, NonEmptyVec<Self::Item>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::Item) -> K;
+
+    /// Splits the elements into runs of consecutive elements, starting a new run whenever
+    /// `predicate` returns `false` for the last element of the current run and the next element.
+    ///
+    /// # Parameters
+    /// - `predicate`: The function deciding whether two consecutive elements belong to the same run.
+    ///
+    /// # Returns
+    /// - The non-empty runs, in order. The vector is empty if the iterator is.
+    fn chunk_by_non_empty<F>(self, predicate: F) -> Vec<NonEmptyVec<Self::Item>>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> bool;
+
+    /// Partitions the elements into the ones matching `predicate` and the others.
+    ///
+    /// # Parameters
+    /// - `predicate`: The function returning `true` for the elements of the first group.
+    ///
+    /// # Returns
+    /// - The elements matching `predicate` and the others, each `None` if there are no such
+    ///   elements.
+    fn partition_non_empty<F>(self, predicate: F) -> NonEmptyPartition<Self::Item>
+    where
+        F: FnMut(&Self::Item) -> bool;
+}
+
+impl<I: Iterator> NonEmptyGroupIterator for I {
+    fn group_by_key<K, F>(self, mut key: F) -> HashMap<K, NonEmptyVec<Self::Item>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::Item) -> K,
+    {
+        let mut groups: HashMap<K, NonEmptyVec<Self::Item>> = HashMap::new();
+        for item in self {
+            match groups.entry(key(&item)) {
+                Entry::Occupied(mut entry) => entry.get_mut().push(item),
+                Entry::Vacant(entry) => {
+                    entry.insert(NonEmptyVec::singleton(item));
+                },
+            }
+        }
+        groups
+    }
+
+    fn chunk_by_non_empty<F>(self, mut predicate: F) -> Vec<NonEmptyVec<Self::Item>>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        let mut chunks: Vec<NonEmptyVec<Self::Item>> = Vec::new();
+        for item in self {
+            match chunks.last_mut() {
+                Some(chunk) if predicate(chunk.last(), &item) => chunk.push(item),
+                _ => chunks.push(NonEmptyVec::singleton(item)),
+            }
+        }
+        chunks
+    }
+
+    fn partition_non_empty<F>(self, predicate: F) -> NonEmptyPartition<Self::Item>
+    where
+        F: FnMut(&Self::Item) -> bool,
+    {
+        let (matching, others): (Vec<Self::Item>, Vec<Self::Item>) = self.partition(predicate);
+        (
+            NonEmptyVec::new(matching).ok(),
+            NonEmptyVec::new(others).ok(),
+        )
+    }
+}
+
+/// An interface for grouping the elements of a slice into non-empty groups, borrowing them.
+pub trait NonEmptyGroupSlice<T> {
+    /// Groups references to the elements by the key returned by `key`, keeping their relative
+    /// order within each group.
+    ///
+    /// # Parameters
+    /// - `key`: The function returning the key of each element.
+    ///
+    /// # Returns
+    /// - A map from each key to the non-empty group of elements having this key.
+    fn group_by_key<K, F>(&self, key: F) -> HashMap<K, NonEmptyVec<&T>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&T) -> K;
+
+    /// Splits the slice into runs of consecutive elements, starting a new run whenever
+    /// `predicate` returns `false` for two consecutive elements.
+    ///
+    /// # Parameters
+    /// - `predicate`: The function deciding whether two consecutive elements belong to the same run.
+    ///
+    /// # Returns
+    /// - An iterator over the non-empty runs, in order.
+    fn chunk_by_non_empty<'a, F>(&'a self, predicate: F) -> impl Iterator<Item = &'a NonEmptySlice<T>>
+    where
+        T: 'a,
+        F: FnMut(&T, &T) -> bool;
+
+    /// Partitions references to the elements into the ones matching `predicate` and the others.
+    ///
+    /// # Parameters
+    /// - `predicate`: The function returning `true` for the elements of the first group.
+    ///
+    /// # Returns
+    /// - The elements matching `predicate` and the others, each `None` if there are no such
+    ///   elements.
+    fn partition_non_empty<F>(&self, predicate: F) -> NonEmptyPartition<&T>
+    where
+        F: FnMut(&T) -> bool;
+}
+
+impl<T> NonEmptyGroupSlice<T> for [T] {
+    fn group_by_key<K, F>(&self, mut key: F) -> HashMap<K, NonEmptyVec<&T>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&T) -> K,
+    {
+        self.iter().group_by_key(|item| key(item))
+    }
+
+    fn chunk_by_non_empty<'a, F>(&'a self, predicate: F) -> impl Iterator<Item = &'a NonEmptySlice<T>>
+    where
+        T: 'a,
+        F: FnMut(&T, &T) -> bool,
+    {
+        // SAFETY: `slice::chunk_by` never yields an empty chunk.
+        self.chunk_by(predicate)
+            .map(|chunk| unsafe { NonEmptySlice::new_unchecked(chunk) })
+    }
+
+    fn partition_non_empty<F>(&self, mut predicate: F) -> NonEmptyPartition<&T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().partition_non_empty(|item| predicate(item))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_by_key() {
+        let groups = (1..=7).group_by_key(|it| it % 3);
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[&0].as_inner(), &[3, 6]);
+        assert_eq!(groups[&1].as_inner(), &[1, 4, 7]);
+        assert_eq!(groups[&2].as_inner(), &[2, 5]);
+
+        let groups = ["a", "bb", "cc", "d"].group_by_key(|it| it.len());
+        assert_eq!(groups[&2].as_inner(), &[&"bb", &"cc"]);
+
+        assert!(std::iter::empty::<i32>().group_by_key(|it| *it).is_empty());
+    }
+
+    #[test]
+    fn test_chunk_by_non_empty() {
+        let chunks = [1, 1, 2, 3, 3, 3]
+            .into_iter()
+            .chunk_by_non_empty(|a, b| a == b);
+        let chunks: Vec<Vec<i32>> = chunks.into_iter().map(NonEmptyVec::into_inner).collect();
+        assert_eq!(chunks, vec![vec![1, 1], vec![2], vec![3, 3, 3]]);
+
+        let data = [1, 2, 3, 1, 2, 0];
+        let runs: Vec<&[i32]> = data
+            .chunk_by_non_empty(|a, b| a < b)
+            .map(NonEmptySlice::as_slice)
+            .collect();
+        assert_eq!(runs, vec![&[1, 2, 3][..], &[1, 2], &[0]]);
+        assert_eq!(
+            data.chunk_by_non_empty(|a, b| a < b)
+                .map(|run| *run.last())
+                .collect::<Vec<_>>(),
+            vec![3, 2, 0]
+        );
+
+        assert!(std::iter::empty::<i32>()
+            .chunk_by_non_empty(|a, b| a == b)
+            .is_empty());
+        assert_eq!(<[i32]>::chunk_by_non_empty(&[], |a, b| a == b).count(), 0);
+    }
+
+    #[test]
+    fn test_partition_non_empty() {
+        let (even, odd) = (1..=5).partition_non_empty(|it| it % 2 == 0);
+        assert_eq!(even.unwrap().as_inner(), &[2, 4]);
+        assert_eq!(odd.unwrap().as_inner(), &[1, 3, 5]);
+
+        let (small, large) = [1, 2, 3].partition_non_empty(|it| *it < 10);
+        assert_eq!(small.unwrap().len().get(), 3);
+        assert!(large.is_none());
+    }
+}
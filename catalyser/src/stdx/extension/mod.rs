@@ -3,9 +3,15 @@
 //!
 //! # Submodules
 //!
+//! - `chunk_extension`: Contains fixed-size chunking operations on slices yielding non-empty
+//!   chunks.
+//! - `group_extension`: Contains grouping and partitioning operations on iterators and slices
+//!   producing non-empty groups.
 //! - `scope_functions_extension`: Contains utilities for working with scope functions like closures
 //!   and higher-order functions.
 //! - `str_extension`: Contains additional utilities for string manipulation.
 
+pub mod chunk_extension;
+pub mod group_extension;
 pub mod scope_functions_extension;
 pub mod str_extension;
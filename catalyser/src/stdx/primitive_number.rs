@@ -9,7 +9,8 @@
 //! - **Floating-point Bounds:** Define bounded floating-point types with predetermined ranges.
 //! - **Serialization:** Supports `serde` for (de)serializing the bounded numbers.
 //! - **Validation:** Provides utilities for creating bounded numbers and validating inputs at
-//!   runtime.
+//!   runtime. Bounded integers also act as element validators for
+//!   [ValidatedCollection](crate::stdx::collections::validated_collection::ValidatedCollection).
 //!
 //! ## Usage
 //!
@@ -150,6 +151,17 @@ macro_rules! generate_bounded_num {
                 self.0.fmt(f)
             }
         }
+
+        impl<const MIN: $type_name, const MAX: $type_name> $crate::stdx::collections::validated_collection::ElementValidator<$type_name>
+            for $name<MIN, MAX>
+        {
+            type Error = OutOfBoundsError<$type_name>;
+
+            /// Validates that the element is within `MIN` and `MAX`.
+            fn validate(element: &$type_name) -> Result<(), Self::Error> {
+                Self::new(*element).map(|_| ())
+            }
+        }
     };
 }
 
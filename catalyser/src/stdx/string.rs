@@ -8,6 +8,8 @@
 //! - [ValidatedString]: A wrapper around [String] that ensures its content complies with the rules
 //!   defined by a [StringContentValidator]. This guarantees that all instances of [ValidatedString]
 //!   are known to be valid according to the associated rules.
+//! - [ValidatedStr]: The borrowed counterpart of [ValidatedString], a wrapper around [str] that
+//!   lets validated content be passed by reference without allocation.
 //!
 //! # Features
 //!
@@ -15,7 +17,23 @@
 //!   statically enforces that string content is valid according to the specified validation rules.
 //!
 //! - **Custom Validators**: You can implement the [StringContentValidator] trait to define custom
-//!   validation logic for specific use cases.
+//!   validation logic for specific use cases, reporting either a [StringContentError] or an error
+//!   type of your own.
+//!
+//! - **Combinators**: [And], [Or] and [Not] compose validators at the type level, reporting every
+//!   violated rule.
+//!
+//! - **Patterns**: With the `regex` feature, `PatternValidator` declares a validator from a
+//!   regular expression, compiled once and cached.
+//!
+//! - **Length Limits**: [MinLen], [MaxLen] and [LenRange] bound the length of a string, counted in
+//!   [Bytes], [Chars] or, with the `unicode-segmentation` feature, grapheme clusters.
+//!
+//! - **Drop-In Strings**: [ValidatedString] dereferences to, borrows as and compares with [str],
+//!   and is created through [FromStr] and [TryFrom], so it fits existing string-based code.
+//!
+//! - **Borrowed Views**: [ValidatedString::as_validated_str] and [ValidatedStr::new] provide
+//!   validated `&str` views, converted back to owned strings through [ToOwned].
 //!
 //! - **Serialization and Deserialization**: [ValidatedString] supports `serde` serialization and
 //!   deserialization, ensuring that validated strings remain valid through these operations.
@@ -26,6 +44,8 @@
 //!   enforce non-empty content.
 //! - [NonBlankString]: A type alias for [ValidatedString] that uses the [NonBlankValidator] to
 //!   enforce non-blank (non-whitespace) content.
+//! - [NonEmptyStr] and [NonBlankStr]: The borrowed counterparts of [NonEmptyString] and
+//!   [NonBlankString].
 //!
 //! ## Examples
 //!
@@ -45,6 +65,18 @@
 //!
 //! let invalid_non_blank = NonBlankString::new("   ".to_string());
 //! assert!(invalid_non_blank.is_err());
+//!
+//! // Using borrowed views
+//! use catalyser::stdx::string::NonBlankStr;
+//!
+//! fn greet(name: &NonBlankStr) -> String {
+//!     format!("Hello, {name}!")
+//! }
+//!
+//! let owned = NonBlankString::new("World".to_string()).unwrap();
+//! assert_eq!(greet(owned.as_validated_str()), "Hello, World!");
+//! assert_eq!(greet(NonBlankStr::new("there").unwrap()), "Hello, there!");
+//! assert!(NonBlankStr::new(" ").is_err());
 //! ```
 //!
 //! # Safety
@@ -57,18 +89,44 @@ use crate::stdx::error::is_empty_or_blank_string::StringContentError;
 #[cfg(feature = "serde")]
 use serde::{de::Error, Deserialize, Serialize};
 use std::{
-    fmt::{Display, Formatter, Result as FmtResult},
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
     marker::PhantomData,
+    ops::Deref,
+    str::FromStr,
 };
 
 /// A trait for validating and creating `ValidatedString` instances with specific content rules.
 ///
 /// This trait is implemented by types that define rules for string content validation. The
-/// `validate_and_create` method is used to create a `ValidatedString` if the provided input
-/// meets the criteria defined in the implementor.
+/// `validate` method checks whether a borrowed input meets the criteria defined in the
+/// implementor, and `validate_and_create` relies on it to create a `ValidatedString` without
+/// copying the input.
 pub trait StringContentValidator: Sized {
+    /// The error reported for an invalid string.
+    ///
+    /// The validators of this module report a `StringContentError`, while custom validators may
+    /// report their own error type.
+    type Error: Debug + Display;
+
+    /// Validates the input string without taking ownership of it.
+    ///
+    /// # Parameters
+    ///
+    /// - `input`: The input string to validate.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())`: If the input meets the validation criteria.
+    /// - `Err(Self::Error)`: If the input fails validation, indicating the error type.
+    fn validate(input: &str) -> Result<(), Self::Error>;
+
     /// Validates and creates a `ValidatedString` instance if the input satisfies the content rules.
     ///
+    /// The default implementation validates the input with `validate` and wraps it as is.
+    ///
     /// # Parameters
     ///
     /// - `input`: The input string to validate.
@@ -76,8 +134,11 @@ pub trait StringContentValidator: Sized {
     /// # Returns
     ///
     /// - `Ok(ValidatedString<Self>)`: If the input meets the validation criteria.
-    /// - `Err(StringContentError)`: If the input fails validation, indicating the error type.
-    fn validate_and_create(input: String) -> Result<ValidatedString<Self>, StringContentError>;
+    /// - `Err(Self::Error)`: If the input fails validation, indicating the error type.
+    fn validate_and_create(input: String) -> Result<ValidatedString<Self>, Self::Error> {
+        Self::validate(&input)?;
+        Ok(ValidatedString(input, PhantomData))
+    }
 }
 
 /// A wrapper around `String` that ensures its content adheres to the rules
@@ -85,7 +146,9 @@ pub trait StringContentValidator: Sized {
 ///
 /// `ValidatedString` uses the generic type parameter `T` to specify the validator
 /// to apply for content validation.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+///
+/// It dereferences to `str` and borrows as `str`, so it can be read, compared and used as a map
+/// key wherever a plain string is expected.
 #[repr(transparent)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
@@ -101,8 +164,8 @@ impl<T: StringContentValidator> ValidatedString<T> {
     /// # Returns
     ///
     /// - `Ok(Self)`: If the input string passes validation.
-    /// - `Err(StringContentError)`: If the input string fails validation with the appropriate error.
-    pub fn new(string: String) -> Result<Self, StringContentError> {
+    /// - `Err(T::Error)`: If the input string fails validation with the appropriate error.
+    pub fn new(string: String) -> Result<Self, T::Error> {
         T::validate_and_create(string)
     }
 
@@ -124,11 +187,136 @@ impl<T: StringContentValidator> ValidatedString<T> {
     pub fn into_inner(self) -> String {
         self.0
     }
+
+    /// Returns the underlying `str`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns a borrowed `ValidatedStr` view over the content.
+    pub fn as_validated_str(&self) -> &ValidatedStr<T> {
+        // SAFETY: the content has been validated by `T` when `self` was created.
+        unsafe { ValidatedStr::new_unchecked(&self.0) }
+    }
+}
+
+impl<T: StringContentValidator> Debug for ValidatedString<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Debug::fmt(&self.0, f)
+    }
 }
 
 impl<T: StringContentValidator> Display for ValidatedString<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        self.0.fmt(f)
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<T: StringContentValidator> Clone for ValidatedString<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<T: StringContentValidator> PartialEq for ValidatedString<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: StringContentValidator> Eq for ValidatedString<T> {}
+
+impl<T: StringContentValidator> PartialEq<str> for ValidatedString<T> {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl<T: StringContentValidator> PartialEq<&str> for ValidatedString<T> {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<T: StringContentValidator> PartialEq<ValidatedString<T>> for str {
+    fn eq(&self, other: &ValidatedString<T>) -> bool {
+        self == other.0
+    }
+}
+
+impl<T: StringContentValidator> PartialEq<ValidatedString<T>> for &str {
+    fn eq(&self, other: &ValidatedString<T>) -> bool {
+        *self == other.0
+    }
+}
+
+impl<T: StringContentValidator> PartialOrd for ValidatedString<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: StringContentValidator> Ord for ValidatedString<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+/// Hashes the content only, consistently with `str`, so that a `ValidatedString` key can be looked
+/// up by `&str`.
+impl<T: StringContentValidator> Hash for ValidatedString<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<T: StringContentValidator> Deref for ValidatedString<T> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: StringContentValidator> AsRef<str> for ValidatedString<T> {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<T: StringContentValidator> Borrow<str> for ValidatedString<T> {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<T: StringContentValidator> Borrow<ValidatedStr<T>> for ValidatedString<T> {
+    fn borrow(&self) -> &ValidatedStr<T> {
+        self.as_validated_str()
+    }
+}
+
+impl<T: StringContentValidator> FromStr for ValidatedString<T> {
+    type Err = T::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s.to_owned())
+    }
+}
+
+impl<T: StringContentValidator> TryFrom<String> for ValidatedString<T> {
+    type Error = T::Error;
+
+    fn try_from(string: String) -> Result<Self, Self::Error> {
+        Self::new(string)
+    }
+}
+
+impl<T: StringContentValidator> TryFrom<&str> for ValidatedString<T> {
+    type Error = T::Error;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        Self::new(string.to_owned())
     }
 }
 
@@ -143,10 +331,123 @@ impl<'de, T: StringContentValidator> Deserialize<'de> for ValidatedString<T> {
     }
 }
 
+/// A borrowed `str` whose content adheres to the rules defined by the `StringContentValidator`
+/// trait.
+///
+/// `ValidatedStr` is to `ValidatedString` what `str` is to `String`.
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct ValidatedStr<T: StringContentValidator>(
+    #[cfg_attr(feature = "serde", serde(skip))] PhantomData<T>,
+    str,
+);
+
+impl<T: StringContentValidator> ValidatedStr<T> {
+    /// Creates a new `ValidatedStr` view by validating the provided input string.
+    ///
+    /// # Parameters
+    ///
+    /// - `string`: The input string to validate and borrow.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(&Self)`: If the input string passes validation.
+    /// - `Err(T::Error)`: If the input string fails validation with the appropriate error.
+    pub fn new(string: &str) -> Result<&Self, T::Error> {
+        T::validate(string)?;
+        // SAFETY: the content has just been validated by `T`.
+        Ok(unsafe { Self::new_unchecked(string) })
+    }
+
+    /// Creates a new `ValidatedStr` view without performing any validation.
+    ///
+    /// # Parameters
+    ///
+    /// - `string`: The input string to borrow.
+    ///
+    /// # Safety
+    ///
+    /// This method is unsafe because it assumes the caller has ensured the input is valid without
+    /// verification.
+    pub unsafe fn new_unchecked(string: &str) -> &Self {
+        // SAFETY: `ValidatedStr<T>` is a `#[repr(transparent)]` wrapper around `str`, so both
+        // references have the same layout and metadata.
+        unsafe { &*(string as *const str as *const Self) }
+    }
+
+    /// Returns the underlying `str`.
+    pub fn as_str(&self) -> &str {
+        &self.1
+    }
+}
+
+impl<T: StringContentValidator> Deref for ValidatedStr<T> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.1
+    }
+}
+
+impl<T: StringContentValidator> AsRef<str> for ValidatedStr<T> {
+    fn as_ref(&self) -> &str {
+        &self.1
+    }
+}
+
+impl<T: StringContentValidator> ToOwned for ValidatedStr<T> {
+    type Owned = ValidatedString<T>;
+
+    fn to_owned(&self) -> Self::Owned {
+        ValidatedString(self.1.to_owned(), PhantomData)
+    }
+}
+
+impl<T: StringContentValidator> Debug for ValidatedStr<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Debug::fmt(&self.1, f)
+    }
+}
+
+impl<T: StringContentValidator> Display for ValidatedStr<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.1, f)
+    }
+}
+
+impl<T: StringContentValidator> PartialEq for ValidatedStr<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.1 == other.1
+    }
+}
+
+impl<T: StringContentValidator> Eq for ValidatedStr<T> {}
+
+impl<T: StringContentValidator> PartialOrd for ValidatedStr<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: StringContentValidator> Ord for ValidatedStr<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.1.cmp(&other.1)
+    }
+}
+
+impl<T: StringContentValidator> Hash for ValidatedStr<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.1.hash(state)
+    }
+}
+
 /// Validator that ensures a string is not empty.
 pub struct NonEmptyValidator;
 
 impl StringContentValidator for NonEmptyValidator {
+    type Error = StringContentError;
+
     /// Validates that the input string is not empty.
     ///
     /// # Parameters
@@ -155,13 +456,13 @@ impl StringContentValidator for NonEmptyValidator {
     ///
     /// # Returns
     ///
-    /// - `Ok(ValidatedString<Self>)` if the input is not empty.
+    /// - `Ok(())` if the input is not empty.
     /// - `Err(StringContentError::Empty)` if the input is empty.
-    fn validate_and_create(input: String) -> Result<ValidatedString<Self>, StringContentError> {
+    fn validate(input: &str) -> Result<(), Self::Error> {
         if input.is_empty() {
             return Err(StringContentError::Empty);
         }
-        Ok(ValidatedString(input, PhantomData))
+        Ok(())
     }
 }
 
@@ -169,6 +470,8 @@ impl StringContentValidator for NonEmptyValidator {
 pub struct NonBlankValidator;
 
 impl StringContentValidator for NonBlankValidator {
+    type Error = StringContentError;
+
     /// Validates that the input string is not blank.
     ///
     /// # Parameters
@@ -177,13 +480,335 @@ impl StringContentValidator for NonBlankValidator {
     ///
     /// # Returns
     ///
-    /// - `Ok(ValidatedString<Self>)` if the input is not blank.
+    /// - `Ok(())` if the input is not blank.
     /// - `Err(StringContentError::Blank)` if the input contains only whitespace.
-    fn validate_and_create(input: String) -> Result<ValidatedString<Self>, StringContentError> {
+    fn validate(input: &str) -> Result<(), Self::Error> {
         if input.trim().is_empty() {
             return Err(StringContentError::Blank(input.to_string()));
         }
-        Ok(ValidatedString(input, PhantomData))
+        Ok(())
+    }
+}
+
+/// Validator combining two validators, requiring the string to satisfy both of them.
+///
+/// Both validators are always checked, so that the error lists every violated rule: the error of
+/// the only failing validator, or `StringContentError::Multiple` if both fail. Nested `And`
+/// validators report a single flat list.
+///
+/// The errors of custom validators are converted into a `StringContentError`, typically wrapped in
+/// `StringContentError::Custom`.
+///
+/// # Examples
+///
+/// ```
+/// use catalyser::stdx::string::{And, NonBlankValidator, Not, ValidatedString};
+///
+/// # use catalyser::stdx::{error::is_empty_or_blank_string::StringContentError, string::StringContentValidator};
+/// # struct ContainsDigitValidator;
+/// # impl StringContentValidator for ContainsDigitValidator {
+/// #     type Error = StringContentError;
+/// #     fn validate(input: &str) -> Result<(), Self::Error> {
+/// #         if input.chars().any(|c| c.is_ascii_digit()) {
+/// #             Ok(())
+/// #         } else {
+/// #             Err(StringContentError::custom("string contains no digit"))
+/// #         }
+/// #     }
+/// # }
+/// type Name = ValidatedString<And<NonBlankValidator, Not<ContainsDigitValidator>>>;
+///
+/// assert!(Name::new("Ada".to_string()).is_ok());
+/// assert!(Name::new("R2D2".to_string()).is_err());
+/// assert!(Name::new(" ".to_string()).is_err());
+/// ```
+pub struct And<A, B>(PhantomData<(A, B)>);
+
+impl<A, B> StringContentValidator for And<A, B>
+where
+    A: StringContentValidator,
+    B: StringContentValidator,
+    A::Error: Into<StringContentError>,
+    B::Error: Into<StringContentError>,
+{
+    type Error = StringContentError;
+
+    fn validate(input: &str) -> Result<(), Self::Error> {
+        match (
+            A::validate(input).map_err(Into::into),
+            B::validate(input).map_err(Into::into),
+        ) {
+            (Ok(()), Ok(())) => Ok(()),
+            (Err(error), Ok(())) | (Ok(()), Err(error)) => Err(error),
+            (Err(first), Err(second)) => {
+                let mut errors = Vec::new();
+                for error in [first, second] {
+                    match error {
+                        StringContentError::Multiple(nested) => errors.extend(nested),
+                        error => errors.push(error),
+                    }
+                }
+                Err(StringContentError::Multiple(errors))
+            },
+        }
+    }
+}
+
+/// Validator combining two validators, requiring the string to satisfy at least one of them.
+///
+/// If neither validator is satisfied, the error is `StringContentError::NoMatch`, listing the
+/// error of every alternative. Nested `Or` validators report a single flat list.
+pub struct Or<A, B>(PhantomData<(A, B)>);
+
+impl<A, B> StringContentValidator for Or<A, B>
+where
+    A: StringContentValidator,
+    B: StringContentValidator,
+    A::Error: Into<StringContentError>,
+    B::Error: Into<StringContentError>,
+{
+    type Error = StringContentError;
+
+    fn validate(input: &str) -> Result<(), Self::Error> {
+        let first = match A::validate(input) {
+            Ok(()) => return Ok(()),
+            Err(error) => error.into(),
+        };
+        let second = match B::validate(input) {
+            Ok(()) => return Ok(()),
+            Err(error) => error.into(),
+        };
+
+        let mut errors = Vec::new();
+        for error in [first, second] {
+            match error {
+                StringContentError::NoMatch(nested) => errors.extend(nested),
+                error => errors.push(error),
+            }
+        }
+        Err(StringContentError::NoMatch(errors))
+    }
+}
+
+/// Validator negating a validator, requiring the string not to satisfy it.
+///
+/// If the negated validator is satisfied, the error is `StringContentError::Negated`.
+pub struct Not<A>(PhantomData<A>);
+
+impl<A> StringContentValidator for Not<A>
+where
+    A: StringContentValidator,
+{
+    type Error = StringContentError;
+
+    fn validate(input: &str) -> Result<(), Self::Error> {
+        match A::validate(input) {
+            Ok(()) => Err(StringContentError::Negated(input.to_string())),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+/// A unit in which the length of a string is counted by the length validators.
+pub trait LengthUnit {
+    /// Returns the length of the input string in this unit.
+    ///
+    /// # Parameters
+    ///
+    /// - `input`: The string to measure.
+    fn length(input: &str) -> usize;
+}
+
+/// Counts the length of a string in bytes of its UTF-8 encoding, as storage limits usually are.
+pub struct Bytes;
+
+impl LengthUnit for Bytes {
+    fn length(input: &str) -> usize {
+        input.len()
+    }
+}
+
+/// Counts the length of a string in Unicode scalar values (`char`s).
+pub struct Chars;
+
+impl LengthUnit for Chars {
+    fn length(input: &str) -> usize {
+        input.chars().count()
+    }
+}
+
+/// Counts the length of a string in extended grapheme clusters, as perceived by a reader.
+#[cfg(feature = "unicode-segmentation")]
+pub struct Graphemes;
+
+#[cfg(feature = "unicode-segmentation")]
+impl LengthUnit for Graphemes {
+    fn length(input: &str) -> usize {
+        unicode_segmentation::UnicodeSegmentation::graphemes(input, true).count()
+    }
+}
+
+/// Checks that the length of the input string, counted in the unit `U`, is between `min` and `max`.
+fn check_length<U: LengthUnit>(input: &str, min: usize, max: usize) -> Result<(), StringContentError> {
+    let actual = U::length(input);
+    if actual < min {
+        return Err(StringContentError::TooShort { min, actual });
+    }
+    if actual > max {
+        return Err(StringContentError::TooLong { max, actual });
+    }
+    Ok(())
+}
+
+/// Validator that ensures a string holds at least `N` units `U` (`char`s by default).
+///
+/// If the string is shorter, the error is `StringContentError::TooShort`.
+pub struct MinLen<const N: usize, U = Chars>(PhantomData<U>);
+
+impl<const N: usize, U: LengthUnit> StringContentValidator for MinLen<N, U> {
+    type Error = StringContentError;
+
+    fn validate(input: &str) -> Result<(), Self::Error> {
+        check_length::<U>(input, N, usize::MAX)
+    }
+}
+
+/// Validator that ensures a string holds at most `N` units `U` (`char`s by default).
+///
+/// If the string is longer, the error is `StringContentError::TooLong`.
+///
+/// # Examples
+///
+/// ```
+/// use catalyser::stdx::string::{And, Bytes, MaxLen, NonBlankValidator, ValidatedString};
+///
+/// // A `VARCHAR(8)` column of a database counting bytes.
+/// type Code = ValidatedString<And<NonBlankValidator, MaxLen<8, Bytes>>>;
+///
+/// assert!(Code::new("ABC-123".to_string()).is_ok());
+/// assert!(Code::new("ÉÉÉ-123".to_string()).is_err());
+/// assert!(Code::new(" ".to_string()).is_err());
+/// ```
+pub struct MaxLen<const N: usize, U = Chars>(PhantomData<U>);
+
+impl<const N: usize, U: LengthUnit> StringContentValidator for MaxLen<N, U> {
+    type Error = StringContentError;
+
+    fn validate(input: &str) -> Result<(), Self::Error> {
+        check_length::<U>(input, 0, N)
+    }
+}
+
+/// Validator that ensures a string holds between `MIN` and `MAX` units `U` (`char`s by default),
+/// both inclusive.
+///
+/// If the string is shorter or longer, the error is `StringContentError::TooShort` or
+/// `StringContentError::TooLong`. Bounds where `MIN` is greater than `MAX` are rejected at compile
+/// time.
+///
+/// # Examples
+///
+/// ```compile_fail
+/// use catalyser::stdx::string::{LenRange, ValidatedString};
+///
+/// let never_valid = ValidatedString::<LenRange<5, 3>>::new("abcd".to_string());
+/// ```
+pub struct LenRange<const MIN: usize, const MAX: usize, U = Chars>(PhantomData<U>);
+
+impl<const MIN: usize, const MAX: usize, U: LengthUnit> StringContentValidator for LenRange<MIN, MAX, U> {
+    type Error = StringContentError;
+
+    fn validate(input: &str) -> Result<(), Self::Error> {
+        const { assert!(MIN <= MAX, "`MIN` must be less than or equal to `MAX`") };
+
+        check_length::<U>(input, MIN, MAX)
+    }
+}
+
+/// A trait for validators matching strings against a regular expression, declared once as a
+/// constant.
+///
+/// Every type implementing this trait is a `StringContentValidator`, so it can be used directly as
+/// the validator of a `ValidatedString`, including through its `Deserialize` implementation. The
+/// regular expression is compiled the first time it is used and cached for the lifetime of the
+/// program.
+///
+/// As with `Regex::is_match`, the pattern matches anywhere in the string unless it is anchored
+/// with `^` and `$`.
+///
+/// # Examples
+///
+/// ```
+/// use catalyser::stdx::string::{PatternValidator, ValidatedString};
+///
+/// struct Slug;
+///
+/// impl PatternValidator for Slug {
+///     const PATTERN: &'static str = r"^[a-z0-9]+(-[a-z0-9]+)*$";
+/// }
+///
+/// assert!(ValidatedString::<Slug>::new("hello-world".to_string()).is_ok());
+/// assert!(ValidatedString::<Slug>::new("Hello World".to_string()).is_err());
+/// ```
+#[cfg(feature = "regex")]
+pub trait PatternValidator {
+    /// The regular expression the strings must match.
+    const PATTERN: &'static str;
+
+    /// Returns the compiled regular expression, compiling it on the first call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `PATTERN` is not a valid regular expression.
+    fn regex() -> &'static regex::Regex {
+        cached_regex(Self::PATTERN)
+    }
+}
+
+/// Returns the compiled regular expression of `pattern`, compiling and caching it on the first
+/// call for this pattern.
+///
+/// The compiled regular expressions are leaked, which is bounded by the number of distinct
+/// patterns declared by `PatternValidator` implementations.
+#[cfg(feature = "regex")]
+fn cached_regex(pattern: &'static str) -> &'static regex::Regex {
+    use std::{
+        collections::HashMap,
+        sync::{OnceLock, PoisonError, RwLock},
+    };
+
+    static CACHE: OnceLock<RwLock<HashMap<&'static str, &'static regex::Regex>>> = OnceLock::new();
+
+    let cache = CACHE.get_or_init(Default::default);
+    if let Some(regex) = cache
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(pattern)
+    {
+        return regex;
+    }
+    cache
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry(pattern)
+        .or_insert_with(|| match regex::Regex::new(pattern) {
+            Ok(regex) => Box::leak(Box::new(regex)),
+            Err(error) => panic!("invalid pattern `{pattern}`: {error}"),
+        })
+}
+
+#[cfg(feature = "regex")]
+impl<P: PatternValidator> StringContentValidator for P {
+    type Error = StringContentError;
+
+    fn validate(input: &str) -> Result<(), Self::Error> {
+        if !P::regex().is_match(input) {
+            return Err(StringContentError::PatternMismatch {
+                pattern: P::PATTERN,
+                value: input.to_string(),
+            });
+        }
+        Ok(())
     }
 }
 
@@ -217,9 +842,40 @@ pub type NonEmptyString = ValidatedString<NonEmptyValidator>;
 /// ```
 pub type NonBlankString = ValidatedString<NonBlankValidator>;
 
+/// A `ValidatedStr` that ensures the content is non-empty.
+///
+/// # Examples
+///
+/// ```
+/// use catalyser::stdx::string::NonEmptyStr;
+///
+/// let valid = NonEmptyStr::new("Hello");
+/// assert!(valid.is_ok());
+///
+/// let empty = NonEmptyStr::new("");
+/// assert!(empty.is_err());
+/// ```
+pub type NonEmptyStr = ValidatedStr<NonEmptyValidator>;
+
+/// A `ValidatedStr` that ensures the content is non-blank.
+///
+/// # Examples
+///
+/// ```
+/// use catalyser::stdx::string::NonBlankStr;
+///
+/// let valid = NonBlankStr::new("Hello");
+/// assert!(valid.is_ok());
+///
+/// let blank = NonBlankStr::new(" \t\n");
+/// assert!(blank.is_err());
+/// ```
+pub type NonBlankStr = ValidatedStr<NonBlankValidator>;
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashMap;
 
     #[test]
     fn test_non_empty_string_new_success() {
@@ -288,6 +944,325 @@ mod tests {
         assert_eq!(format!("{}", non_blank), input);
     }
 
+    #[test]
+    fn test_validated_str_new() {
+        let non_empty = NonEmptyStr::new(" \t\n");
+        assert!(non_empty.is_ok());
+        assert_eq!(non_empty.unwrap().as_str(), " \t\n");
+        assert!(matches!(
+            NonEmptyStr::new(""),
+            Err(StringContentError::Empty)
+        ));
+
+        let non_blank = NonBlankStr::new("Hello");
+        assert!(non_blank.is_ok());
+        assert_eq!(non_blank.unwrap().as_str(), "Hello");
+        assert!(matches!(
+            NonBlankStr::new(" \t\n"),
+            Err(StringContentError::Blank(_))
+        ));
+    }
+
+    #[test]
+    fn test_validated_str_borrowing() {
+        let owned = NonBlankString::new("Hello".to_string()).unwrap();
+
+        let borrowed = owned.as_validated_str();
+        assert_eq!(borrowed, NonBlankStr::new("Hello").unwrap());
+        assert_eq!(Borrow::<NonBlankStr>::borrow(&owned), borrowed);
+        assert_eq!(borrowed.len(), 5);
+        assert_eq!(format!("{borrowed}"), "Hello");
+        assert_eq!(format!("{borrowed:?}"), "\"Hello\"");
+        assert_eq!(borrowed.to_owned().into_inner(), "Hello");
+    }
+
+    #[test]
+    fn test_validated_string_as_str() {
+        let non_blank = NonBlankString::new("Hello".to_string()).unwrap();
+
+        assert_eq!(non_blank.as_str(), "Hello");
+        assert_eq!(non_blank.len(), 5);
+        assert!(non_blank.starts_with("He"));
+        assert_eq!(AsRef::<str>::as_ref(&non_blank), "Hello");
+        assert_eq!(format!("{non_blank:?}"), "\"Hello\"");
+
+        assert_eq!(non_blank, "Hello");
+        assert_eq!(non_blank, *"Hello");
+        assert_eq!("Hello", non_blank);
+        assert_ne!(non_blank, "World");
+        assert_eq!(non_blank.clone(), non_blank);
+        assert!(non_blank < NonBlankString::new("World".to_string()).unwrap());
+    }
+
+    #[test]
+    fn test_validated_string_map_key() {
+        let mut map = HashMap::new();
+        map.insert(NonEmptyString::new("key".to_string()).unwrap(), 1);
+
+        assert_eq!(map.get("key"), Some(&1));
+        assert_eq!(map.get("other"), None);
+    }
+
+    #[test]
+    fn test_validated_string_conversions() {
+        let parsed: NonBlankString = "Hello".parse().unwrap();
+        assert_eq!(parsed, "Hello");
+        assert!(matches!(
+            "  ".parse::<NonBlankString>(),
+            Err(StringContentError::Blank(_))
+        ));
+
+        assert!(NonEmptyString::try_from("Hello".to_string()).is_ok());
+        assert!(matches!(
+            NonEmptyString::try_from(""),
+            Err(StringContentError::Empty)
+        ));
+    }
+
+    /// Validator that ensures a string is lowercase, for combinator tests.
+    struct LowercaseValidator;
+
+    impl StringContentValidator for LowercaseValidator {
+        type Error = StringContentError;
+
+        fn validate(input: &str) -> Result<(), Self::Error> {
+            if input.chars().any(char::is_uppercase) {
+                return Err(StringContentError::custom(
+                    "string contains uppercase letters",
+                ));
+            }
+            Ok(())
+        }
+    }
+
+    /// Error of the username validator, for custom error tests.
+    #[derive(Debug, PartialEq)]
+    enum UsernameError {
+        Reserved(String),
+    }
+
+    impl Display for UsernameError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            match self {
+                UsernameError::Reserved(value) => write!(f, "username `{value}` is reserved"),
+            }
+        }
+    }
+
+    impl std::error::Error for UsernameError {}
+
+    impl From<UsernameError> for StringContentError {
+        fn from(error: UsernameError) -> Self {
+            StringContentError::custom(error)
+        }
+    }
+
+    /// Validator reporting its own error type, for custom error tests.
+    struct UsernameValidator;
+
+    impl StringContentValidator for UsernameValidator {
+        type Error = UsernameError;
+
+        fn validate(input: &str) -> Result<(), Self::Error> {
+            if input == "admin" {
+                return Err(UsernameError::Reserved(input.to_string()));
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_custom_error_validator() {
+        type Username = ValidatedString<UsernameValidator>;
+
+        assert!(Username::new("ada".to_string()).is_ok());
+        assert_eq!(
+            Username::new("admin".to_string()).unwrap_err(),
+            UsernameError::Reserved("admin".to_string())
+        );
+        assert_eq!(
+            "admin".parse::<Username>().unwrap_err(),
+            UsernameError::Reserved("admin".to_string())
+        );
+        assert!(ValidatedStr::<UsernameValidator>::new("admin").is_err());
+
+        let error = And::<NonBlankValidator, UsernameValidator>::validate("admin").unwrap_err();
+        assert_eq!(format!("{error:?}"), "Custom(Reserved(\"admin\"))");
+        assert_eq!(error.to_string(), "username `admin` is reserved");
+        assert!(std::error::Error::source(&error).is_none());
+
+        let error = StringContentError::custom("must be lowercase");
+        assert_eq!(error.to_string(), "must be lowercase");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_custom_error_validator_serde() {
+        let username: ValidatedString<UsernameValidator> = serde_json::from_str(r#""ada""#).unwrap();
+        assert_eq!(username, "ada");
+
+        let error = serde_json::from_str::<ValidatedString<UsernameValidator>>(r#""admin""#).unwrap_err();
+        assert!(error.to_string().contains("username `admin` is reserved"));
+    }
+
+    #[test]
+    fn test_and_validator() {
+        type Lowercase = ValidatedString<And<NonBlankValidator, LowercaseValidator>>;
+
+        assert!(Lowercase::new("hello".to_string()).is_ok());
+        assert!(matches!(
+            Lowercase::new(" ".to_string()),
+            Err(StringContentError::Blank(_))
+        ));
+        let error = Lowercase::new("Hello".to_string()).unwrap_err();
+        assert_eq!(
+            format!("{error:?}"),
+            "Custom(\"string contains uppercase letters\")"
+        );
+        assert_eq!(error.to_string(), "string contains uppercase letters");
+
+        let error = And::<LowercaseValidator, MinLen<3>>::validate("Hi").unwrap_err();
+        assert_eq!(
+            format!("{error:?}"),
+            "Multiple(Custom(\"string contains uppercase letters\"), TooShort(min = 3, actual = 2))"
+        );
+
+        type Strict = ValidatedString<And<And<NonEmptyValidator, NonBlankValidator>, Not<NonBlankValidator>>>;
+        let error = Strict::new("".to_string()).unwrap_err();
+        assert_eq!(format!("{error:?}"), "Multiple(Empty, Blank(value = ``))");
+        assert_eq!(
+            error.to_string(),
+            "string violates several rules (string is empty; string is blank (content: ``))"
+        );
+    }
+
+    #[test]
+    fn test_or_validator() {
+        type EmptyOrLowercase = ValidatedString<Or<Not<NonEmptyValidator>, LowercaseValidator>>;
+
+        assert!(EmptyOrLowercase::new("".to_string()).is_ok());
+        assert!(EmptyOrLowercase::new("hello".to_string()).is_ok());
+
+        let error = EmptyOrLowercase::new("Hello".to_string()).unwrap_err();
+        assert_eq!(
+            format!("{error:?}"),
+            "NoMatch(Negated(value = `Hello`), Custom(\"string contains uppercase letters\"))"
+        );
+
+        type Nested = Or<Or<NonBlankValidator, NonBlankValidator>, NonBlankValidator>;
+        assert!(matches!(Nested::validate(" "), Err(StringContentError::NoMatch(errors)) if errors.len() == 3));
+    }
+
+    #[test]
+    fn test_not_validator() {
+        type NotBlank = ValidatedStr<Not<Not<NonBlankValidator>>>;
+
+        assert!(NotBlank::new("hello").is_ok());
+        assert!(matches!(
+            NotBlank::new(" "),
+            Err(StringContentError::Negated(_))
+        ));
+        assert_eq!(
+            Not::<NonEmptyValidator>::validate("a")
+                .unwrap_err()
+                .to_string(),
+            "string satisfies a negated rule (content: `a`)"
+        );
+    }
+
+    #[test]
+    fn test_length_validators() {
+        assert!(ValidatedString::<MinLen<2>>::new("ab".to_string()).is_ok());
+        assert!(matches!(
+            ValidatedStr::<MinLen<2>>::new("a"),
+            Err(StringContentError::TooShort { min: 2, actual: 1 })
+        ));
+
+        // "été" holds 3 chars but 5 bytes.
+        assert!(ValidatedStr::<MaxLen<3>>::new("été").is_ok());
+        let error = ValidatedStr::<MaxLen<3, Bytes>>::new("été").unwrap_err();
+        assert_eq!(format!("{error:?}"), "TooLong(max = 3, actual = 5)");
+        assert_eq!(
+            error.to_string(),
+            "string is too long (maximum: 3, actual: 5)"
+        );
+
+        type Username = LenRange<3, 5>;
+        assert!(Username::validate("abc").is_ok());
+        assert!(Username::validate("abcde").is_ok());
+        assert!(matches!(
+            Username::validate("ab"),
+            Err(StringContentError::TooShort { .. })
+        ));
+        assert!(matches!(
+            Username::validate("abcdef"),
+            Err(StringContentError::TooLong { .. })
+        ));
+
+        let error = And::<NonBlankValidator, LenRange<1, 4>>::validate("      ").unwrap_err();
+        assert_eq!(
+            format!("{error:?}"),
+            "Multiple(Blank(value = `      `), TooLong(max = 4, actual = 6))"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "unicode-segmentation")]
+    fn test_length_validators_graphemes() {
+        // "e" followed by a combining acute accent is a single grapheme cluster of 2 chars.
+        let input = "e\u{301}";
+        assert!(MaxLen::<1, Graphemes>::validate(input).is_ok());
+        assert!(matches!(
+            MaxLen::<1, Chars>::validate(input),
+            Err(StringContentError::TooLong { max: 1, actual: 2 })
+        ));
+    }
+
+    #[cfg(feature = "regex")]
+    struct HexColor;
+
+    #[cfg(feature = "regex")]
+    impl PatternValidator for HexColor {
+        const PATTERN: &'static str = "^#[0-9a-fA-F]{6}$";
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn test_pattern_validator() {
+        assert!(ValidatedString::<HexColor>::new("#1a2B3c".to_string()).is_ok());
+        assert!(std::ptr::eq(HexColor::regex(), HexColor::regex()));
+
+        let error = ValidatedStr::<HexColor>::new("#12345").unwrap_err();
+        assert!(matches!(
+            &error,
+            StringContentError::PatternMismatch { pattern: "^#[0-9a-fA-F]{6}$", value } if value == "#12345"
+        ));
+        assert_eq!(
+            error.to_string(),
+            "string does not match the pattern `^#[0-9a-fA-F]{6}$` (content: `#12345`)"
+        );
+
+        let error = And::<MaxLen<4>, HexColor>::validate("red").unwrap_err();
+        assert!(matches!(error, StringContentError::PatternMismatch { .. }));
+    }
+
+    #[test]
+    #[cfg(all(feature = "regex", feature = "serde"))]
+    fn test_pattern_validator_serde() {
+        #[derive(Debug, Deserialize)]
+        struct Theme {
+            primary: ValidatedString<HexColor>,
+        }
+
+        let theme: Theme = serde_json::from_str(r##"{"primary":"#ff8800"}"##).unwrap();
+        assert_eq!(theme.primary, "#ff8800");
+
+        let error = serde_json::from_str::<Theme>(r#"{"primary":"orange"}"#).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("string does not match the pattern"));
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn test_non_empty_string_serde() {
@@ -316,5 +1291,9 @@ mod tests {
         // Deserialize
         let deserialized: NonBlankString = serde_json::from_str(&serialized).unwrap();
         assert_eq!(deserialized.into_inner(), input);
+
+        // Serialize a borrowed view
+        let serialized = serde_json::to_string(non_blank.as_validated_str()).unwrap();
+        assert_eq!(serialized, format!("\"{}\"", input));
     }
 }